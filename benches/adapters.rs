@@ -0,0 +1,257 @@
+//! Compares lending adapters with std iterators and hand-written loops.
+//!
+//! Run with `cargo bench`, optionally followed by `-- <filter>` to only run the
+//! benchmarks whose name contains `<filter>`. Uses only `std`, so it runs offline
+//! and on stable.
+
+use std::time::{Duration, Instant};
+
+use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+
+const N: u64 = 10_000;
+
+/// Hides `x` from the optimizer.
+///
+/// `std::hint::black_box` is newer than the minimum supported Rust version.
+fn black_box<T>(x: T) -> T {
+    // SAFETY: `x` is forgotten, so it is moved out exactly once.
+    let copy = unsafe { std::ptr::read_volatile(&x) };
+    std::mem::forget(x);
+    copy
+}
+
+struct Bencher {
+    filter: Option<String>,
+}
+
+impl Bencher {
+    /// Runs `f` until it has taken at least 100ms, and prints the time per run.
+    fn bench<R>(&self, name: &str, mut f: impl FnMut() -> R) {
+        if self.filter.as_ref().map_or(false, |filter| !name.contains(filter.as_str())) {
+            return;
+        }
+        for _ in 0..10 {
+            black_box(f());
+        }
+        let mut runs = 1_u32;
+        loop {
+            let start = Instant::now();
+            for _ in 0..runs {
+                black_box(f());
+            }
+            let elapsed = start.elapsed();
+            if elapsed >= Duration::from_millis(100) {
+                let nanos = elapsed.as_secs_f64() * 1e9 / f64::from(runs);
+                println!("{name:<40} {nanos:>12.0} ns/iter");
+                return;
+            }
+            runs *= 2;
+        }
+    }
+}
+
+fn windows(b: &Bencher, data: &[u64]) {
+    b.bench("windows/lending", || {
+        let mut windows = (0..black_box(N)).windows(3);
+        let mut sum = 0_u64;
+        while let Some(w) = windows.next() {
+            sum = sum.wrapping_add(w[0] * w[2]);
+        }
+        sum
+    });
+    b.bench("windows/slice", || {
+        let mut sum = 0_u64;
+        for w in black_box(data).windows(3) {
+            sum = sum.wrapping_add(w[0] * w[2]);
+        }
+        sum
+    });
+    b.bench("windows/loop", || {
+        let (mut first, mut second) = (0, 1);
+        let mut sum = 0_u64;
+        for third in 2..black_box(N) {
+            sum = sum.wrapping_add(first * third);
+            (first, second) = (second, third);
+        }
+        sum
+    });
+    b.bench("windows/nth", || {
+        let mut windows = (0..black_box(N)).windows(3);
+        let mut sum = 0_u64;
+        while let Some(w) = windows.nth(99) {
+            sum = sum.wrapping_add(w[0]);
+        }
+        sum
+    });
+    b.bench("windows/skip", || {
+        let mut windows = (0..black_box(N)).windows(3).skip(N as usize / 2);
+        let mut sum = 0_u64;
+        while let Some(w) = windows.next() {
+            sum = sum.wrapping_add(w[0]);
+        }
+        sum
+    });
+}
+
+fn windows_mut(b: &Bencher, data: &[u64]) {
+    b.bench("windows_mut/lending", || {
+        let mut windows = (0..black_box(N)).windows_mut(2);
+        let mut last = 0;
+        while let Some(w) = windows.next() {
+            w[1] = w[1].wrapping_add(w[0]);
+            last = w[1];
+        }
+        last
+    });
+    b.bench("windows_mut/loop", || {
+        let mut data = black_box(data).to_vec();
+        for i in 1..data.len() {
+            data[i] = data[i].wrapping_add(data[i - 1]);
+        }
+        data[data.len() - 1]
+    });
+}
+
+fn filter(b: &Bencher) {
+    b.bench("filter/lending", || {
+        let mut filter = (0..black_box(N)).into_lending().filter(|x: &u64| x % 3 == 0);
+        let mut sum = 0_u64;
+        while let Some(x) = filter.next() {
+            sum += x;
+        }
+        sum
+    });
+    b.bench("filter/lending/fold", || {
+        (0..black_box(N))
+            .into_lending()
+            .filter(|x: &u64| x % 3 == 0)
+            .fold(0_u64, |sum, x| sum + x)
+    });
+    b.bench("filter/std", || {
+        (0..black_box(N)).filter(|x| x % 3 == 0).sum::<u64>()
+    });
+    b.bench("filter/loop", || {
+        let mut sum = 0_u64;
+        for x in 0..black_box(N) {
+            if x % 3 == 0 {
+                sum += x;
+            }
+        }
+        sum
+    });
+}
+
+fn filter_map(b: &Bencher) {
+    b.bench("filter_map/lending", || {
+        let mut filter_map = (0..black_box(N))
+            .into_lending()
+            .filter_map(|x: u64| if x % 3 == 0 { Some(x / 3) } else { None });
+        let mut sum = 0_u64;
+        while let Some(x) = filter_map.next() {
+            sum += x;
+        }
+        sum
+    });
+    b.bench("filter_map/std", || {
+        (0..black_box(N))
+            .filter_map(|x| if x % 3 == 0 { Some(x / 3) } else { None })
+            .sum::<u64>()
+    });
+    b.bench("filter_map/loop", || {
+        let mut sum = 0_u64;
+        for x in 0..black_box(N) {
+            if x % 3 == 0 {
+                sum += x / 3;
+            }
+        }
+        sum
+    });
+}
+
+fn step_by(b: &Bencher) {
+    b.bench("step_by/lending", || {
+        let mut step_by = (0..black_box(N)).into_lending().step_by(7);
+        let mut sum = 0_u64;
+        while let Some(x) = step_by.next() {
+            sum += x;
+        }
+        sum
+    });
+    b.bench("step_by/std", || (0..black_box(N)).step_by(7).sum::<u64>());
+    b.bench("step_by/loop", || {
+        let mut sum = 0_u64;
+        let mut x = 0;
+        while x < black_box(N) {
+            sum += x;
+            x += 7;
+        }
+        sum
+    });
+    b.bench("step_by/windows", || {
+        let mut step_by = (0..black_box(N)).windows(3).step_by(7);
+        let mut sum = 0_u64;
+        while let Some(w) = step_by.next() {
+            sum += w[0];
+        }
+        sum
+    });
+}
+
+fn chain(b: &Bencher, data: &[u64]) {
+    b.bench("chain/lending", || {
+        let mut chain = (0..black_box(N))
+            .into_lending()
+            .chain((0..black_box(N)).into_lending());
+        let mut sum = 0_u64;
+        while let Some(x) = chain.next() {
+            sum += x;
+        }
+        sum
+    });
+    b.bench("chain/std", || {
+        (0..black_box(N)).chain(0..black_box(N)).sum::<u64>()
+    });
+    b.bench("chain/windows/next", || {
+        let mut chain = (0..black_box(N)).windows(3).chain((0..black_box(N)).windows(3));
+        let mut sum = 0_u64;
+        while let Some(w) = chain.next() {
+            sum = sum.wrapping_add(w[0] * w[2]);
+        }
+        sum
+    });
+    b.bench("chain/windows/fold", || {
+        (0..black_box(N))
+            .windows(3)
+            .chain((0..black_box(N)).windows(3))
+            .fold(0_u64, |sum, w| sum.wrapping_add(w[0] * w[2]))
+    });
+    b.bench("chain/windows/slice", || {
+        let data = black_box(data);
+        data.windows(3)
+            .chain(data.windows(3))
+            .fold(0_u64, |sum, w| sum.wrapping_add(w[0] * w[2]))
+    });
+    b.bench("chain/loop", || {
+        let mut sum = 0_u64;
+        for x in 0..black_box(N) {
+            sum += x;
+        }
+        for x in 0..black_box(N) {
+            sum += x;
+        }
+        sum
+    });
+}
+
+fn main() {
+    let b = Bencher {
+        filter: std::env::args().skip(1).find(|arg| !arg.starts_with("--")),
+    };
+    let data: Vec<u64> = (0..N).collect();
+    windows(&b, &data);
+    windows_mut(&b, &data);
+    filter(&b);
+    filter_map(&b);
+    step_by(&b);
+    chain(&b, &data);
+}
@@ -0,0 +1,82 @@
+use core::fmt;
+
+/// A mutable borrow of a lending iterator, for adapters that take in a closure.
+///
+/// Applying such an adapter to `&'b mut I` requires the closure to accept
+/// the items of `&'b mut I` for every lifetime, which the compiler can only prove
+/// when `'b` is `'static`. Adapters over `ByRef` only refer to the items of `I` itself,
+/// so they work with any borrow of a `'static` lending iterator.
+///
+/// The resulting adapter still has a lifetime parameter, so consumers that take
+/// a closure over its items (such as [`for_each`] or [`fold`]) run into the same
+/// limitation. Drive it with [`next`] instead, or with adapters that don't take a closure.
+///
+/// `ByRef` deliberately doesn't implement [`LendingIterator`] itself,
+/// so that it can't be confused with `&mut I`.
+///
+/// This `struct` is created by methods such as [`filter_by_ref`] on [`LendingIterator`]. See
+/// their documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`for_each`]: crate::LendingIterator::for_each
+/// [`fold`]: crate::LendingIterator::fold
+/// [`next`]: crate::LendingIterator::next
+/// [`filter_by_ref`]: crate::LendingIterator::filter_by_ref
+pub struct ByRef<'a, I: ?Sized> {
+    pub(crate) iter: &'a mut I,
+}
+
+impl<'a, I: ?Sized> ByRef<'a, I> {
+    pub(crate) fn new(iter: &'a mut I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<I: fmt::Debug + ?Sized> fmt::Debug for ByRef<'_, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByRef").field("iter", &self.iter).finish()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn filter_then_resume() {
+        let mut windows = (0..10).windows(3);
+        let mut evens = windows.filter_by_ref(|w| w[0] % 2 == 0).take(2);
+        assert_eq!(evens.next(), Some(&[0, 1, 2][..]));
+        assert_eq!(evens.next(), Some(&[2, 3, 4][..]));
+        assert_eq!(windows.next(), Some(&[3, 4, 5][..]));
+    }
+
+    #[test]
+    fn take_while_then_resume() {
+        let mut windows = (0..10).windows(2);
+        assert_eq!(windows.take_while_by_ref(|w| w[1] < 4).count(), 3);
+        // the first window failing the predicate is consumed, like with `Iterator::take_while`
+        assert_eq!(windows.next(), Some(&[4, 5][..]));
+    }
+
+    #[test]
+    fn skip_while_then_resume() {
+        let mut windows = (0..10).windows(2);
+        assert_eq!(windows.skip_while_by_ref(|w| w[0] < 3).next(), Some(&[3, 4][..]));
+        assert_eq!(windows.next(), Some(&[4, 5][..]));
+    }
+
+    #[test]
+    fn map_and_filter_map_then_resume() {
+        let mut windows = (0..10).windows(2);
+        let mut sums = Vec::new();
+        let mut mapped = windows.map_by_ref(|w: &[i32]| w[0] + w[1]).take(2);
+        while let Some(sum) = mapped.next() {
+            sums.push(sum);
+        }
+        assert_eq!(sums, vec![1, 3]);
+        let mut firsts = windows.filter_map_by_ref(|w: &[i32]| (w[0] % 3 == 0).then_some(w[0]));
+        assert_eq!(firsts.next(), Some(3));
+        assert_eq!(windows.next(), Some(&[4, 5][..]));
+    }
+}
@@ -1,4 +1,6 @@
-use crate::LendingIterator;
+use core::num::NonZeroUsize;
+
+use crate::{LendingIterator, Resettable};
 
 /// A lending iterator that iterates over the elements of two iterators
 /// in sequence.
@@ -6,6 +8,53 @@ use crate::LendingIterator;
 /// This `struct` is created by the [`chain`] method on [`LendingIterator`]. See
 /// its documentation for more.
 ///
+/// # Limitations
+///
+/// Requiring both halves to lend the same items means requiring
+/// `B::Item<'a> == A::Item<'a>` for *every* lifetime `'a`.
+/// Because of the `where Self: 'a` clause on [`LendingIterator::Item`],
+/// the compiler can currently only prove that when both lending iterators are `'static`,
+/// so chaining lending iterators that borrow local data is rejected:
+///
+/// ```compile_fail
+/// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+///
+/// let a = vec![1, 2];
+/// let b = vec![3];
+/// a.iter().lend_refs().chain(b.iter().lend_refs()).for_each(|x| println!("{x}"));
+/// ```
+///
+/// Dropping the `+ 'a` doesn't help: the adapter itself then fails to compile, because
+/// normalizing `B::Item<'a>` needs `B: 'a`. Neither does moving the equality into a helper
+/// trait, even one whose impl carries `B: 'a` as an implied bound: the compiler proves the
+/// impl's where clauses for every `'a` without that assumption, so it still needs `B: 'static`.
+///
+/// Lending iterators of the same type lend the same items without that bound, so
+/// [`chain_same`](crate::LendingIterator::chain_same) chains them even when they borrow
+/// local data. Adapters taking closures, like `for_each`, still need `'static` lending
+/// iterators, so this drives it with `next`:
+///
+/// ```
+/// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+///
+/// let a = vec![1, 2];
+/// let b = vec![3];
+/// let mut chain = a.iter().lend_refs().chain_same(b.iter().lend_refs());
+/// while let Some(x) = chain.next() {
+///     println!("{x}");
+/// }
+/// ```
+///
+/// Otherwise, build the lending iterators from owning iterators:
+///
+/// ```
+/// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+///
+/// let a = vec![1, 2];
+/// let b = vec![3];
+/// a.into_iter().lend_refs().chain(b.into_iter().lend_refs()).for_each(|x| println!("{x}"));
+/// ```
+///
 /// [`LendingIterator`]: crate::LendingIterator
 /// [`chain`]: crate::LendingIterator::chain
 #[derive(Clone, Debug)]
@@ -15,6 +64,7 @@ pub struct Chain<A, B> {
     b: B,
     a_done: bool,
 }
+
 impl<A, B> Chain<A, B> {
     pub(crate) fn new(a: A, b: B) -> Chain<A, B> {
         Chain {
@@ -46,4 +96,223 @@ where
             })
         }
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.a_done {
+            return self.b.size_hint();
+        }
+        size_hint(self.a.size_hint(), self.b.size_hint())
+    }
+
+    #[inline]
+    fn advance_by(&mut self, mut n: usize) -> Result<(), NonZeroUsize> {
+        if !self.a_done {
+            n = match self.a.advance_by(n) {
+                Ok(()) => return Ok(()),
+                Err(remaining) => remaining.get(),
+            };
+            self.a_done = true;
+        }
+        self.b.advance_by(n)
+    }
+
+    #[inline]
+    fn fold<Acc, F>(self, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, A::Item<'_>) -> Acc,
+    {
+        let acc = if self.a_done {
+            init
+        } else {
+            self.a.fold(init, &mut f)
+        };
+        self.b.fold(acc, f)
+    }
+}
+
+impl<A, B> Resettable for Chain<A, B>
+where
+    A: Resettable,
+    for<'a> B: Resettable + LendingIterator<Item<'a> = A::Item<'a>> + 'a,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.a.reset();
+        self.b.reset();
+        self.a_done = false;
+    }
+}
+
+/// A lending iterator that iterates over the elements of two lending iterators of the
+/// same type in sequence.
+///
+/// Unlike [`Chain`], it works with lending iterators that borrow local data.
+///
+/// This `struct` is created by the [`chain_same`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`chain_same`]: crate::LendingIterator::chain_same
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChainSame<I> {
+    a: I,
+    b: I,
+    a_done: bool,
+}
+
+impl<I> ChainSame<I> {
+    pub(crate) fn new(a: I, b: I) -> ChainSame<I> {
+        ChainSame {
+            a,
+            b,
+            a_done: false,
+        }
+    }
+}
+
+impl<I: LendingIterator> LendingIterator for ChainSame<I> {
+    type Item<'a> = I::Item<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item<'_>> {
+        if self.a_done {
+            self.b.next()
+        } else {
+            self.a.next().or_else(|| {
+                self.a_done = true;
+                self.b.next()
+            })
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.a_done {
+            return self.b.size_hint();
+        }
+        size_hint(self.a.size_hint(), self.b.size_hint())
+    }
+
+    #[inline]
+    fn advance_by(&mut self, mut n: usize) -> Result<(), NonZeroUsize> {
+        if !self.a_done {
+            n = match self.a.advance_by(n) {
+                Ok(()) => return Ok(()),
+                Err(remaining) => remaining.get(),
+            };
+            self.a_done = true;
+        }
+        self.b.advance_by(n)
+    }
+
+    #[inline]
+    fn fold<Acc, F>(self, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, I::Item<'_>) -> Acc,
+    {
+        let acc = if self.a_done {
+            init
+        } else {
+            self.a.fold(init, &mut f)
+        };
+        self.b.fold(acc, f)
+    }
+}
+
+impl<I: Resettable> Resettable for ChainSame<I> {
+    #[inline]
+    fn reset(&mut self) {
+        self.a.reset();
+        self.b.reset();
+        self.a_done = false;
+    }
+}
+
+// The size hint of a lending iterator with hint `a` followed by one with hint `b`.
+fn size_hint(a: (usize, Option<usize>), b: (usize, Option<usize>)) -> (usize, Option<usize>) {
+    let (a_lower, a_upper) = a;
+    let (b_lower, b_upper) = b;
+    let lower = a_lower.saturating_add(b_lower);
+    let upper = match (a_upper, b_upper) {
+        (Some(a), Some(b)) => a.checked_add(b),
+        _ => None,
+    };
+    (lower, upper)
+}
+
+/// Checks that spelling the bound with a helper trait still rejects borrowed halves,
+/// as documented in [`Chain`]'s limitations.
+///
+/// ```compile_fail
+/// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+///
+/// trait SameItems<'a, A: LendingIterator, Bound = &'a (Self, A)>: LendingIterator {
+///     fn cast(item: Self::Item<'a>) -> A::Item<'a>;
+/// }
+///
+/// impl<'a, A, B> SameItems<'a, A, &'a (B, A)> for B
+/// where
+///     A: LendingIterator,
+///     B: LendingIterator<Item<'a> = A::Item<'a>>,
+/// {
+///     fn cast(item: Self::Item<'a>) -> A::Item<'a> {
+///         item
+///     }
+/// }
+///
+/// struct Chain<A, B>(A, B, bool);
+///
+/// impl<A, B> LendingIterator for Chain<A, B>
+/// where
+///     A: LendingIterator,
+///     B: for<'a> SameItems<'a, A>,
+/// {
+///     type Item<'a> = A::Item<'a> where Self: 'a;
+///
+///     fn next(&mut self) -> Option<Self::Item<'_>> {
+///         if !self.2 {
+///             self.2 = true;
+///             return self.0.next();
+///         }
+///         self.1.next().map(B::cast)
+///     }
+/// }
+///
+/// let (a, b) = (vec![1, 2], vec![3]);
+/// let mut chain = Chain(a.iter().lend_refs(), b.iter().lend_refs(), false);
+/// chain.next();
+/// ```
+#[cfg(doctest)]
+struct HelperTraitChain;
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn chain_same_borrowed() {
+        let a = [1, 2];
+        let b = [3];
+        let mut chain = a.iter().lend_refs().chain_same(b.iter().lend_refs());
+        assert_eq!(chain.size_hint(), (3, Some(3)));
+        assert_eq!(chain.next(), Some(&&1));
+        assert_eq!(chain.nth(1), Some(&&3));
+        assert_eq!(chain.next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn chain_same_borrowed_windows() {
+        let mut buffer = [1, 2, 3, 4];
+        let (left, right) = buffer.split_at_mut(2);
+        let mut windows = left.iter_mut().windows_mut(2).chain_same(right.iter_mut().windows_mut(2));
+        while let Some(window) = windows.next() {
+            *window[1] += *window[0];
+        }
+        assert_eq!(buffer, [1, 3, 3, 7]);
+    }
 }
@@ -1,6 +1,6 @@
-use std::ops::Deref;
+use core::ops::Deref;
 
-use crate::LendingIterator;
+use crate::{ExactSizeLendingIterator, LendingIterator, Resettable};
 
 /// A lending iterator that clones the elements of an underlying lending iterator.
 ///
@@ -34,6 +34,39 @@ where
     fn next(&mut self) -> Option<Self::Item<'_>> {
         self.iter.next().map(|item| item.deref().clone())
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, <I::Item<'_> as Deref>::Target) -> B,
+    {
+        self.iter.fold(init, move |acc, item| f(acc, item.deref().clone()))
+    }
+}
+
+impl<I> ExactSizeLendingIterator for Cloned<I>
+where
+    I: ExactSizeLendingIterator,
+    for<'a> I::Item<'a>: Deref,
+    for<'a> <I::Item<'a> as Deref>::Target: Clone,
+{
+}
+
+impl<I> Resettable for Cloned<I>
+where
+    I: Resettable,
+    for<'a> I::Item<'a>: Deref,
+    for<'a> <I::Item<'a> as Deref>::Target: Clone,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+    }
 }
 
 pub struct IntoIter<I> {
@@ -51,6 +84,11 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(|item| item.deref().clone())
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
 impl<I, T> IntoIterator for Cloned<I>
@@ -0,0 +1,123 @@
+use core::ops::Deref;
+
+use crate::{ExactSizeLendingIterator, LendingIterator, Resettable};
+
+/// A lending iterator that copies the elements of an underlying lending iterator.
+///
+/// This `struct` is created by the [`copied`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`copied`]: crate::LendingIterator::copied
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Copied<I> {
+    iter: I,
+}
+
+impl<I> Copied<I> {
+    pub(crate) fn new(iter: I) -> Copied<I> {
+        Copied { iter }
+    }
+}
+
+impl<I> LendingIterator for Copied<I>
+where
+    I: LendingIterator,
+    for<'a> I::Item<'a>: Deref,
+    for<'a> <I::Item<'a> as Deref>::Target: Copy,
+{
+    type Item<'a> = <I::Item<'a> as Deref>::Target
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        self.iter.next().map(|item| *item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, <I::Item<'_> as Deref>::Target) -> B,
+    {
+        self.iter.fold(init, move |acc, item| f(acc, *item))
+    }
+}
+
+impl<I> ExactSizeLendingIterator for Copied<I>
+where
+    I: ExactSizeLendingIterator,
+    for<'a> I::Item<'a>: Deref,
+    for<'a> <I::Item<'a> as Deref>::Target: Copy,
+{
+}
+
+pub struct IntoIter<I> {
+    iter: I,
+}
+
+impl<I, T> Iterator for IntoIter<I>
+where
+    I: LendingIterator,
+    for<'a> I::Item<'a>: Deref<Target = T>,
+    T: Copy,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|item| *item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> IntoIterator for Copied<I>
+where
+    I: LendingIterator,
+    for<'a> I::Item<'a>: Deref<Target = T>,
+    T: Copy,
+{
+    type Item = T;
+    type IntoIter = IntoIter<I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { iter: self.iter }
+    }
+}
+
+impl<I> Resettable for Copied<I>
+where
+    I: Resettable,
+    for<'a> I::Item<'a>: Deref,
+    for<'a> <I::Item<'a> as Deref>::Target: Copy,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+
+    fn second(w: &[u8]) -> &u8 {
+        &w[1]
+    }
+
+    #[test]
+    fn copied() {
+        let mut copied = (0..5).windows(3).map(second).copied().into_iter();
+        assert_eq!(copied.next(), Some(1));
+        let rest: Vec<_> = copied.collect();
+        assert_eq!(rest, vec![2, 3]);
+    }
+}
@@ -0,0 +1,285 @@
+use core::num::NonZeroUsize;
+
+use crate::{LendingIterator, Resettable};
+
+/// A lending iterator that repeats endlessly.
+///
+/// This `struct` is created by the [`cycle`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`cycle`]: crate::LendingIterator::cycle
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Cycle<I> {
+    orig: I,
+    iter: I,
+}
+
+impl<I: Clone> Cycle<I> {
+    pub(crate) fn new(iter: I) -> Cycle<I> {
+        Cycle {
+            orig: iter.clone(),
+            iter,
+        }
+    }
+}
+
+impl<I> LendingIterator for Cycle<I>
+where
+    I: Clone + LendingIterator,
+{
+    type Item<'a> = I::Item<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        #[cfg(not(feature = "polonius"))]
+        // SAFETY: see https://docs.rs/polonius-the-crab/0.3.1/polonius_the_crab/#the-arcanemagic
+        let iter = unsafe { &mut *core::ptr::addr_of_mut!(self.iter) };
+        #[cfg(feature = "polonius")]
+        let iter = &mut self.iter;
+        if let Some(item) = iter.next() {
+            return Some(item);
+        }
+        self.iter.clone_from(&self.orig);
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.orig.size_hint() {
+            (0, Some(0)) => (0, Some(0)),
+            (0, _) => (0, None),
+            _ => (usize::MAX, None),
+        }
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let mut n = match self.iter.advance_by(n) {
+            Ok(()) => return Ok(()),
+            Err(rem) => rem.get(),
+        };
+        // Stop once a whole cycle makes no progress, so empty iterators terminate.
+        while n > 0 {
+            self.iter.clone_from(&self.orig);
+            n = match self.iter.advance_by(n) {
+                Ok(()) => return Ok(()),
+                Err(rem) if rem.get() == n => return Err(rem),
+                Err(rem) => rem.get(),
+            };
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, I::Item<'_>) -> B,
+    {
+        let mut acc = self.iter.fold(init, &mut f);
+        // Only ends if a whole cycle is empty.
+        loop {
+            let mut empty = true;
+            acc = self.orig.clone().fold(acc, |acc, item| {
+                empty = false;
+                f(acc, item)
+            });
+            if empty {
+                return acc;
+            }
+        }
+    }
+}
+
+/// A lending iterator that repeats endlessly by resetting the underlying lending iterator.
+///
+/// This `struct` is created by the [`reset_cycle`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`reset_cycle`]: crate::LendingIterator::reset_cycle
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ResetCycle<I> {
+    iter: I,
+}
+
+impl<I> ResetCycle<I> {
+    pub(crate) fn new(iter: I) -> ResetCycle<I> {
+        ResetCycle { iter }
+    }
+}
+
+impl<I: Resettable> LendingIterator for ResetCycle<I> {
+    type Item<'a> = I::Item<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        #[cfg(not(feature = "polonius"))]
+        // SAFETY: see https://docs.rs/polonius-the-crab/0.3.1/polonius_the_crab/#the-arcanemagic
+        let iter = unsafe { &mut *core::ptr::addr_of_mut!(self.iter) };
+        #[cfg(feature = "polonius")]
+        let iter = &mut self.iter;
+        if let Some(item) = iter.next() {
+            return Some(item);
+        }
+        self.iter.reset();
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Only an exhausted lending iterator might turn out to be empty after a reset.
+        match self.iter.size_hint() {
+            (0, _) => (0, None),
+            _ => (usize::MAX, None),
+        }
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let mut n = match self.iter.advance_by(n) {
+            Ok(()) => return Ok(()),
+            Err(rem) => rem.get(),
+        };
+        while n > 0 {
+            self.iter.reset();
+            n = match self.iter.advance_by(n) {
+                Ok(()) => return Ok(()),
+                Err(rem) if rem.get() == n => return Err(rem),
+                Err(rem) => rem.get(),
+            };
+        }
+        Ok(())
+    }
+
+    // The underlying lending iterator has to be kept to be reset, so it can't be
+    // folded, but its items are lent without the reborrow `next` needs.
+    #[inline]
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, I::Item<'_>) -> B,
+    {
+        let mut acc = init;
+        while let Some(item) = self.iter.next() {
+            acc = f(acc, item);
+        }
+        // Only ends if a whole cycle is empty.
+        loop {
+            self.iter.reset();
+            let mut empty = true;
+            while let Some(item) = self.iter.next() {
+                empty = false;
+                acc = f(acc, item);
+            }
+            if empty {
+                return acc;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, Resettable};
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn cycle() {
+        use crate::ToLendingIterator;
+
+        let mut cycle = (0..3).windows(2).cycle();
+        for _ in 0..3 {
+            assert_eq!(cycle.next(), Some(&[0, 1][..]));
+            assert_eq!(cycle.next(), Some(&[1, 2][..]));
+        }
+        assert_eq!((0..1).windows(2).cycle().next(), None);
+        assert_eq!((0..0).into_lending().cycle().size_hint(), (0, Some(0)));
+    }
+
+    // Lends windows of a buffer it owns, in place.
+    struct Chunks {
+        buf: [u8; 4],
+        pos: usize,
+    }
+
+    impl LendingIterator for Chunks {
+        type Item<'a> = &'a mut [u8] where Self: 'a;
+
+        fn next(&mut self) -> Option<Self::Item<'_>> {
+            let chunk = self.buf.get_mut(self.pos..self.pos + 2)?;
+            self.pos += 2;
+            Some(chunk)
+        }
+    }
+
+    impl Resettable for Chunks {
+        fn reset(&mut self) {
+            self.pos = 0;
+        }
+    }
+
+    #[test]
+    fn reset_cycle() {
+        let chunks = Chunks {
+            buf: [0, 1, 2, 3],
+            pos: 0,
+        };
+        let mut cycle = chunks
+            .map(|chunk: &mut [u8]| {
+                chunk[0] += 10;
+                chunk[0]
+            })
+            .reset_cycle();
+        let firsts: [u8; 5] = core::array::from_fn(|_| cycle.next().unwrap());
+        assert_eq!(firsts, [10, 12, 20, 22, 30]);
+
+        let empty = Chunks {
+            buf: [0; 4],
+            pos: 4,
+        };
+        let mut empty = empty.take_while(|_| false).reset_cycle();
+        assert_eq!(empty.next(), None);
+    }
+
+    #[test]
+    fn advance_by() {
+        use crate::ToLendingIterator;
+
+        let mut cycle = (0..3).into_lending().cycle();
+        assert_eq!(cycle.advance_by(7), Ok(()));
+        assert_eq!(cycle.next(), Some(1));
+        let mut empty = (0..0).into_lending().cycle();
+        assert_eq!(empty.advance_by(2).map_err(usize::from), Err(2));
+
+        let chunks = Chunks {
+            buf: [0, 1, 2, 3],
+            pos: 0,
+        };
+        let mut cycle = chunks.reset_cycle();
+        assert_eq!(cycle.advance_by(3), Ok(()));
+        assert_eq!(cycle.next(), Some(&mut [2, 3][..]));
+        let mut empty = (Chunks { buf: [0; 4], pos: 0 }).take_while(|_| false).reset_cycle();
+        assert_eq!(empty.advance_by(2).map_err(usize::from), Err(2));
+    }
+
+    #[test]
+    fn fold() {
+        use crate::ToLendingIterator;
+
+        // The rest of the current cycle is lent before an empty one ends the fold.
+        let cycle = super::Cycle {
+            orig: (0..0).into_lending(),
+            iter: (1..4).into_lending(),
+        };
+        assert_eq!(cycle.fold(0, |acc, x| acc * 10 + x), 123);
+
+        let empty = (Chunks { buf: [0; 4], pos: 0 }).take_while(|_| false).reset_cycle();
+        assert_eq!(empty.fold(0, |acc, _| acc + 1), 0);
+    }
+}
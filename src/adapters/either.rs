@@ -0,0 +1,152 @@
+use core::num::NonZeroUsize;
+
+use crate::{ExactSizeLendingIterator, LendingIterator};
+
+/// A lending iterator that is one of two lending iterator types that lend the same items.
+///
+/// This is useful for branching between two differently typed lending iterators,
+/// for example `windows(3)` and `windows(3).skip(1)`, without writing a new enum each time.
+/// Like [`Chain`], the right hand side must lend the same items as the left hand side.
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use gat_lending_iterator::{Either, LendingIterator, ToLendingIterator};
+///
+/// let skip_first = true;
+/// let mut windows = if skip_first {
+///     Either::Right((0..5).windows(3).skip(1))
+/// } else {
+///     Either::Left((0..5).windows(3))
+/// };
+/// assert_eq!(windows.next(), Some(&[1, 2, 3][..]));
+/// # }
+/// ```
+///
+/// # Limitations
+///
+/// Like with [`Chain`], proving that both sides lend the same items for every
+/// lifetime currently requires both sides to be `'static`, so branching between
+/// lending iterators that borrow local data is rejected, even if only `next` is called:
+///
+/// ```compile_fail
+/// use gat_lending_iterator::{Either, LendingIterator, ToLendingIterator};
+///
+/// let data = vec![0, 1, 2, 3, 4];
+/// let mut windows = if data.len() > 3 {
+///     Either::Right(data.iter().windows(3).skip(1))
+/// } else {
+///     Either::Left(data.iter().windows(3))
+/// };
+/// windows.next();
+/// ```
+///
+/// Build the sides from owning iterators instead, for example `data.clone().into_iter()`.
+/// See [`Chain`'s limitations](crate::Chain#limitations) for more.
+///
+/// [`Chain`]: crate::Chain
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub enum Either<L, R> {
+    /// The left lending iterator.
+    Left(L),
+    /// The right lending iterator.
+    Right(R),
+}
+
+impl<L, R> LendingIterator for Either<L, R>
+where
+    L: LendingIterator,
+    for<'a> R: LendingIterator<Item<'a> = L::Item<'a>> + 'a,
+{
+    type Item<'a> = L::Item<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        match self {
+            Either::Left(l) => l.next(),
+            Either::Right(r) => r.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Either::Left(l) => l.size_hint(),
+            Either::Right(r) => r.size_hint(),
+        }
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        match self {
+            Either::Left(l) => l.advance_by(n),
+            Either::Right(r) => r.advance_by(n),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        match self {
+            Either::Left(l) => l.nth(n),
+            Either::Right(r) => r.nth(n),
+        }
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, L::Item<'_>) -> B,
+    {
+        match self {
+            Either::Left(l) => l.fold(init, f),
+            Either::Right(r) => r.fold(init, f),
+        }
+    }
+}
+
+impl<L, R> ExactSizeLendingIterator for Either<L, R>
+where
+    L: ExactSizeLendingIterator,
+    for<'a> R: ExactSizeLendingIterator<Item<'a> = L::Item<'a>> + 'a,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        match self {
+            Either::Left(l) => l.len(),
+            Either::Right(r) => r.len(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+    use crate::{Skip, ToLendingIterator, Windows};
+    use core::ops::Range;
+
+    fn windows(skip: bool) -> impl for<'a> LendingIterator<Item<'a> = &'a [i32]> {
+        if skip {
+            Either::Right((0..5).windows(3).skip(1))
+        } else {
+            Either::Left((0..5).windows(3))
+        }
+    }
+
+    #[test]
+    fn test() {
+        assert_eq!(windows(false).next(), Some(&[0, 1, 2][..]));
+        assert_eq!(windows(true).next(), Some(&[1, 2, 3][..]));
+        assert_eq!(windows(false).count(), 3);
+        assert_eq!(windows(true).count(), 2);
+    }
+
+    #[test]
+    fn exact_size() {
+        let left: Either<_, Skip<Windows<Range<i32>>>> = Either::Left((0..5).windows(3));
+        assert_eq!(left.len(), 3);
+        let right: Either<Windows<Range<i32>>, _> = Either::Right((0..5).windows(3).skip(1));
+        assert_eq!(right.len(), 2);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::LendingIterator;
+use core::num::NonZeroUsize;
+
+use crate::{ExactSizeLendingIterator, LendingIterator, Resettable};
 
 /// A lending iterator that yields the current count and the element during iteration.
 #[derive(Clone, Debug)]
@@ -24,51 +26,59 @@ impl<I: LendingIterator> LendingIterator for Enumerate<I> {
         self.count += 1;
         Some((count, item))
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::ToLendingIterator;
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 
-    // A non-fused iterator for testing that we match std's behavior
-    struct Delay<I> {
-        countdown: usize,
-        iter: I,
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let result = self.iter.advance_by(n);
+        let advanced = match result {
+            Ok(()) => n,
+            Err(remaining) => n - remaining.get(),
+        };
+        self.count += advanced;
+        result
     }
 
-    impl<I> Delay<I> {
-        fn new(countdown: usize, iter: I) -> Self {
-            Delay { countdown, iter }
-        }
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        let item = self.iter.nth(n)?;
+        let count = self.count + n;
+        self.count = count + 1;
+        Some((count, item))
     }
 
-    // Generally we avoid implementing both Iterator and LendingIterator
-    // for the same type. Here in testing the bounds of the arguments
-    // are known not to collide.
-    impl<I: LendingIterator> LendingIterator for Delay<I> {
-        type Item<'a> = I::Item<'a> where Self: 'a;
-        fn next(&mut self) -> Option<Self::Item<'_>> {
-            if self.countdown == 0 {
-                self.iter.next()
-            } else {
-                self.countdown -= 1;
-                None
-            }
-        }
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, (usize, I::Item<'_>)) -> B,
+    {
+        let mut count = self.count;
+        self.iter.fold(init, move |acc, item| {
+            let acc = f(acc, (count, item));
+            count += 1;
+            acc
+        })
     }
+}
+
+impl<I: ExactSizeLendingIterator> ExactSizeLendingIterator for Enumerate<I> {}
 
-    impl<I: Iterator> Iterator for Delay<I> {
-        type Item = I::Item;
-        fn next(&mut self) -> Option<Self::Item> {
-            if self.countdown == 0 {
-                self.iter.next()
-            } else {
-                self.countdown -= 1;
-                None
-            }
-        }
+impl<I: Resettable> Resettable for Enumerate<I> {
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+        self.count = 0;
     }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{testing::Delay, ToLendingIterator};
 
     #[test]
     fn test() {
@@ -1,4 +1,4 @@
-use crate::LendingIterator;
+use crate::{ByRef, LendingIterator, Resettable};
 use core::fmt;
 
 /// A lending iterator that filters the elements of `iter` with `predicate`.
@@ -38,16 +38,74 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item<'_>> {
-        loop {
-            // SAFETY: see https://docs.rs/polonius-the-crab/0.3.1/polonius_the_crab/#the-arcanemagic
-            let self_ = unsafe { &mut *(self as *mut Self) };
-            if let Some(item) = self_.iter.next() {
-                if (self_.predicate)(&item) {
-                    return Some(item);
-                }
+        filter_next(&mut self.iter, &mut self.predicate)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, I::Item<'_>) -> B,
+    {
+        let mut predicate = self.predicate;
+        self.iter.fold(init, move |acc, item| {
+            if predicate(&item) {
+                f(acc, item)
             } else {
-                return None;
+                acc
+            }
+        })
+    }
+}
+
+impl<I, P> LendingIterator for Filter<ByRef<'_, I>, P>
+where
+    I: LendingIterator + ?Sized,
+    P: for<'a> FnMut(&I::Item<'a>) -> bool,
+{
+    type Item<'a> = I::Item<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        filter_next(self.iter.iter, &mut self.predicate)
+    }
+}
+
+#[inline]
+pub(crate) fn filter_next<'a, I, P>(iter: &'a mut I, predicate: &mut P) -> Option<I::Item<'a>>
+where
+    I: LendingIterator + ?Sized,
+    P: FnMut(&I::Item<'_>) -> bool,
+{
+    loop {
+        #[cfg(not(feature = "polonius"))]
+        // SAFETY: see https://docs.rs/polonius-the-crab/0.3.1/polonius_the_crab/#the-arcanemagic
+        let iter_ = unsafe { &mut *(iter as *mut I) };
+        #[cfg(feature = "polonius")]
+        let iter_ = &mut *iter;
+        if let Some(item) = iter_.next() {
+            if predicate(&item) {
+                return Some(item);
             }
+        } else {
+            return None;
         }
     }
 }
+
+impl<I, P> Resettable for Filter<I, P>
+where
+    I: Resettable,
+    P: for<'a> FnMut(&I::Item<'a>) -> bool,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+    }
+}
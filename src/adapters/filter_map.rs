@@ -1,4 +1,4 @@
-use crate::{LendingIterator, OptionTrait, SingleArgFnMut, SingleArgFnOnce};
+use crate::{ByRef, LendingIterator, OptionTrait, Resettable, SingleArgFnMut, SingleArgFnOnce};
 use core::fmt;
 
 /// A lending iterator that uses `f` to both filter and map elements from `iter`.
@@ -41,17 +41,78 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item<'_>> {
-        loop {
-            // SAFETY: see https://docs.rs/polonius-the-crab/0.3.1/polonius_the_crab/#the-arcanemagic
-            let self_ = unsafe { &mut *(self as *mut Self) };
-            if let Some(item) = self_.iter.next() {
-                let output = (self_.f)(item).into_option();
-                if output.is_some() {
-                    return output;
-                }
-            } else {
-                return None;
+        filter_map_next(&mut self.iter, &mut self.f)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+
+    #[inline]
+    fn fold<B, G>(self, init: B, mut g: G) -> B
+    where
+        G: FnMut(B, <<F as SingleArgFnOnce<I::Item<'_>>>::Output as OptionTrait>::Item) -> B,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, item| match f(item).into_option() {
+            Some(item) => g(acc, item),
+            None => acc,
+        })
+    }
+}
+
+impl<I, F> LendingIterator for FilterMap<ByRef<'_, I>, F>
+where
+    I: LendingIterator + ?Sized,
+    F: for<'a> SingleArgFnMut<I::Item<'a>>,
+    for<'a> <F as SingleArgFnOnce<I::Item<'a>>>::Output: OptionTrait,
+{
+    type Item<'a> = <<F as SingleArgFnOnce<I::Item<'a>>>::Output as OptionTrait>::Item
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        filter_map_next(self.iter.iter, &mut self.f)
+    }
+}
+
+#[inline]
+fn filter_map_next<'a, I, F>(
+    iter: &'a mut I,
+    f: &mut F,
+) -> Option<<<F as SingleArgFnOnce<I::Item<'a>>>::Output as OptionTrait>::Item>
+where
+    I: LendingIterator + ?Sized,
+    F: for<'b> SingleArgFnMut<I::Item<'b>>,
+    for<'b> <F as SingleArgFnOnce<I::Item<'b>>>::Output: OptionTrait,
+{
+    loop {
+        #[cfg(not(feature = "polonius"))]
+        // SAFETY: see https://docs.rs/polonius-the-crab/0.3.1/polonius_the_crab/#the-arcanemagic
+        let iter_ = unsafe { &mut *(iter as *mut I) };
+        #[cfg(feature = "polonius")]
+        let iter_ = &mut *iter;
+        if let Some(item) = iter_.next() {
+            let output = f(item).into_option();
+            if output.is_some() {
+                return output;
             }
+        } else {
+            return None;
         }
     }
 }
+
+impl<I, F> Resettable for FilterMap<I, F>
+where
+    I: Resettable,
+    F: for<'a> SingleArgFnMut<I::Item<'a>>,
+    for<'a> <F as SingleArgFnOnce<I::Item<'a>>>::Output: OptionTrait,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+    }
+}
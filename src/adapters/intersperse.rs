@@ -0,0 +1,234 @@
+use core::{borrow::Borrow, fmt};
+
+use crate::{ExactSizeLendingIterator, LendingIterator};
+
+/// A lending iterator that lends a separator between the elements of an underlying
+/// lending iterator.
+///
+/// This `struct` is created by the [`intersperse`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`intersperse`]: crate::LendingIterator::intersperse
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Intersperse<I, S> {
+    iter: I,
+    separator: S,
+    needs_sep: bool,
+}
+
+impl<I, S> Intersperse<I, S> {
+    pub(crate) fn new(iter: I, separator: S) -> Self {
+        Self {
+            iter,
+            separator,
+            needs_sep: false,
+        }
+    }
+}
+
+impl<I, S, T> LendingIterator for Intersperse<I, S>
+where
+    for<'a> I: ExactSizeLendingIterator + LendingIterator<Item<'a> = &'a T> + 'a,
+    S: Borrow<T>,
+    T: ?Sized + 'static,
+{
+    type Item<'a> = &'a T
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        if self.needs_sep && !self.iter.is_empty() {
+            self.needs_sep = false;
+            return Some(self.separator.borrow());
+        }
+        let item = self.iter.next()?;
+        self.needs_sep = true;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        intersperse_size_hint(&self.iter, self.needs_sep)
+    }
+
+    #[inline]
+    fn fold<B, G>(self, init: B, mut g: G) -> B
+    where
+        G: FnMut(B, &T) -> B,
+    {
+        let Self {
+            iter,
+            separator,
+            mut needs_sep,
+        } = self;
+        iter.fold(init, move |acc, item| {
+            let acc = if needs_sep { g(acc, separator.borrow()) } else { acc };
+            needs_sep = true;
+            g(acc, item)
+        })
+    }
+}
+
+impl<I, S, T> ExactSizeLendingIterator for Intersperse<I, S>
+where
+    for<'a> I: ExactSizeLendingIterator + LendingIterator<Item<'a> = &'a T> + 'a,
+    S: Borrow<T>,
+    T: ?Sized + 'static,
+{
+}
+
+/// A lending iterator that lends a separator produced by a closure between
+/// the elements of an underlying lending iterator.
+///
+/// This `struct` is created by the [`intersperse_with`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`intersperse_with`]: crate::LendingIterator::intersperse_with
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntersperseWith<I, F, S> {
+    iter: I,
+    separator: Option<S>,
+    f: F,
+    needs_sep: bool,
+}
+
+impl<I, F, S> IntersperseWith<I, F, S> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            separator: None,
+            f,
+            needs_sep: false,
+        }
+    }
+}
+
+impl<I: fmt::Debug, F, S: fmt::Debug> fmt::Debug for IntersperseWith<I, F, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntersperseWith")
+            .field("iter", &self.iter)
+            .field("separator", &self.separator)
+            .field("needs_sep", &self.needs_sep)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, F, S, T> LendingIterator for IntersperseWith<I, F, S>
+where
+    for<'a> I: ExactSizeLendingIterator + LendingIterator<Item<'a> = &'a T> + 'a,
+    F: FnMut() -> S,
+    S: Borrow<T>,
+    T: ?Sized + 'static,
+{
+    type Item<'a> = &'a T
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        if self.needs_sep && !self.iter.is_empty() {
+            self.needs_sep = false;
+            let separator = self.separator.insert((self.f)());
+            return Some((*separator).borrow());
+        }
+        let item = self.iter.next()?;
+        self.needs_sep = true;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        intersperse_size_hint(&self.iter, self.needs_sep)
+    }
+
+    #[inline]
+    fn fold<B, G>(self, init: B, mut g: G) -> B
+    where
+        G: FnMut(B, &T) -> B,
+    {
+        let Self {
+            iter,
+            mut f,
+            mut needs_sep,
+            ..
+        } = self;
+        iter.fold(init, move |acc, item| {
+            let acc = if needs_sep {
+                let separator = f();
+                g(acc, separator.borrow())
+            } else {
+                acc
+            };
+            needs_sep = true;
+            g(acc, item)
+        })
+    }
+}
+
+impl<I, F, S, T> ExactSizeLendingIterator for IntersperseWith<I, F, S>
+where
+    for<'a> I: ExactSizeLendingIterator + LendingIterator<Item<'a> = &'a T> + 'a,
+    F: FnMut() -> S,
+    S: Borrow<T>,
+    T: ?Sized + 'static,
+{
+}
+
+fn intersperse_size_hint<I>(iter: &I, needs_sep: bool) -> (usize, Option<usize>)
+where
+    I: LendingIterator + ?Sized,
+{
+    // Every remaining item is preceded by a separator, except the very first one.
+    let size = |n: usize| match n {
+        0 => Some(0),
+        n if needs_sep => n.checked_mul(2),
+        n => n.checked_mul(2).map(|n| n - 1),
+    };
+    let (lower, upper) = iter.size_hint();
+    (size(lower).unwrap_or(usize::MAX), upper.and_then(size))
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use alloc::vec::Vec;
+
+    use crate::{ExactSizeLendingIterator, LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn intersperse() {
+        let mut iter = (0..4).windows(2).intersperse([9]);
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next(), Some(&[0, 1][..]));
+        assert_eq!(iter.next(), Some(&[9][..]));
+        assert_eq!(iter.len(), 3);
+        let rest = iter.fold(Vec::new(), |mut v, w| {
+            v.push(w.to_vec());
+            v
+        });
+        assert_eq!(rest, vec![vec![1, 2], vec![9], vec![2, 3]]);
+
+        assert_eq!((0..0).windows(2).intersperse([9]).count(), 0);
+        assert_eq!((0..2).windows(2).intersperse([9]).count(), 1);
+    }
+
+    #[test]
+    fn intersperse_with() {
+        let mut calls = 0;
+        let joined = (0..4)
+            .windows(2)
+            .intersperse_with(move || {
+                calls += 1;
+                vec![calls; calls]
+            })
+            .fold(Vec::new(), |mut v, w| {
+                v.extend_from_slice(w);
+                v
+            });
+        assert_eq!(joined, vec![0, 1, 1, 1, 2, 2, 2, 2, 3]);
+    }
+}
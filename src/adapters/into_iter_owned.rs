@@ -0,0 +1,83 @@
+use core::marker::PhantomData;
+
+use crate::LendingIterator;
+
+/// An iterator over the owned items of a lending iterator.
+///
+/// This `struct` is created by the [`into_iter_owned`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`into_iter_owned`]: crate::LendingIterator::into_iter_owned
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntoIterOwned<I, T> {
+    iter: I,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<I, T> IntoIterOwned<I, T> {
+    pub(crate) fn new(iter: I) -> IntoIterOwned<I, T> {
+        IntoIterOwned {
+            iter,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<I, T> Iterator for IntoIterOwned<I, T>
+where
+    for<'a> I: LendingIterator<Item<'a> = T> + 'a,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.iter.nth(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn into_iter_owned() {
+        let evens = (0..10)
+            .into_lending()
+            .filter(|x: &usize| x % 2 == 0)
+            .take(4)
+            .into_iter_owned()
+            .map(|x| x * 10);
+        assert_eq!(evens.sum::<usize>(), 120);
+
+        let mut skipped = (0..10).into_lending().skip(7).into_iter_owned();
+        assert_eq!(skipped.size_hint(), (3, Some(3)));
+        assert_eq!(skipped.nth(1), Some(8));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn windows_round_trip() {
+        fn sum(w: &[usize]) -> usize {
+            w.iter().sum()
+        }
+        let sums: Vec<_> = (0..5)
+            .windows(2)
+            .map(sum)
+            .into_iter_owned()
+            .filter(|&x| x > 4)
+            .collect();
+        assert_eq!(sums, vec![5, 7]);
+    }
+}
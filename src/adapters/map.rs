@@ -1,4 +1,4 @@
-use crate::{LendingIterator, SingleArgFnMut, SingleArgFnOnce};
+use crate::{ByRef, ExactSizeLendingIterator, LendingIterator, Resettable, SingleArgFnMut, SingleArgFnOnce};
 use core::fmt;
 
 /// A lending iterator that maps the elements of `iter` with `f`.
@@ -40,6 +40,58 @@ where
     fn next(&mut self) -> Option<Self::Item<'_>> {
         self.iter.next().map(&mut self.f)
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<B, G>(self, init: B, mut g: G) -> B
+    where
+        G: FnMut(B, <F as SingleArgFnOnce<I::Item<'_>>>::Output) -> B,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, item| g(acc, f(item)))
+    }
+}
+
+impl<I, F> LendingIterator for Map<ByRef<'_, I>, F>
+where
+    I: LendingIterator + ?Sized,
+    F: for<'a> SingleArgFnMut<I::Item<'a>>,
+{
+    type Item<'a> = <F as SingleArgFnOnce<I::Item<'a>>>::Output
+        where
+            Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        self.iter.iter.next().map(&mut self.f)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.iter.size_hint()
+    }
+}
+
+impl<I, F> ExactSizeLendingIterator for Map<I, F>
+where
+    I: ExactSizeLendingIterator,
+    F: for<'a> SingleArgFnMut<I::Item<'a>>,
+{
+}
+
+impl<I, F> Resettable for Map<I, F>
+where
+    I: Resettable,
+    F: for<'a> SingleArgFnMut<I::Item<'a>>,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+    }
 }
 
 /// An iterator that maps the elements of `iter` with `f`.
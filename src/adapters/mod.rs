@@ -1,21 +1,38 @@
+mod by_ref;
 mod chain;
 mod cloned;
+mod copied;
+mod cycle;
+mod either;
 mod enumerate;
 mod filter;
 mod filter_map;
+mod intersperse;
+mod into_iter_owned;
 mod map;
+#[cfg(feature = "alloc")]
+mod owned;
 mod skip;
 mod step_by;
 mod take;
 mod take_while;
 mod zip;
 mod skip_while;
-pub use self::chain::Chain;
+pub use self::by_ref::ByRef;
+pub use self::chain::{Chain, ChainSame};
 pub use self::cloned::Cloned;
+pub use self::copied::Copied;
+pub use self::cycle::{Cycle, ResetCycle};
+pub use self::either::Either;
 pub use self::enumerate::Enumerate;
 pub use self::filter::Filter;
+pub(crate) use self::filter::filter_next;
 pub use self::filter_map::FilterMap;
+pub use self::intersperse::{Intersperse, IntersperseWith};
+pub use self::into_iter_owned::IntoIterOwned;
 pub use self::map::{IntoIter, Map};
+#[cfg(feature = "alloc")]
+pub use self::owned::Owned;
 pub use self::skip::Skip;
 pub use self::step_by::StepBy;
 pub use self::take::Take;
@@ -0,0 +1,130 @@
+use alloc::borrow::ToOwned;
+use core::ops::Deref;
+
+use crate::{ExactSizeLendingIterator, LendingIterator, Resettable};
+
+/// A lending iterator that converts the elements of an underlying lending iterator
+/// to owned values with [`ToOwned`].
+///
+/// This `struct` is created by the [`owned`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`owned`]: crate::LendingIterator::owned
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Owned<I> {
+    iter: I,
+}
+
+impl<I> Owned<I> {
+    pub(crate) fn new(iter: I) -> Owned<I> {
+        Owned { iter }
+    }
+}
+
+impl<I> LendingIterator for Owned<I>
+where
+    I: LendingIterator,
+    for<'a> I::Item<'a>: Deref,
+    for<'a> <I::Item<'a> as Deref>::Target: ToOwned,
+{
+    type Item<'a> = <<I::Item<'a> as Deref>::Target as ToOwned>::Owned
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        self.iter.next().map(|item| item.deref().to_owned())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, <<I::Item<'_> as Deref>::Target as ToOwned>::Owned) -> B,
+    {
+        self.iter.fold(init, move |acc, item| f(acc, item.deref().to_owned()))
+    }
+}
+
+impl<I> ExactSizeLendingIterator for Owned<I>
+where
+    I: ExactSizeLendingIterator,
+    for<'a> I::Item<'a>: Deref,
+    for<'a> <I::Item<'a> as Deref>::Target: ToOwned,
+{
+}
+
+pub struct IntoIter<I> {
+    iter: I,
+}
+
+impl<I, T> Iterator for IntoIter<I>
+where
+    I: LendingIterator,
+    for<'a> I::Item<'a>: Deref<Target = T>,
+    T: ToOwned + ?Sized,
+{
+    type Item = T::Owned;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|item| item.deref().to_owned())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> IntoIterator for Owned<I>
+where
+    I: LendingIterator,
+    for<'a> I::Item<'a>: Deref<Target = T>,
+    T: ToOwned + ?Sized,
+{
+    type Item = T::Owned;
+    type IntoIter = IntoIter<I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { iter: self.iter }
+    }
+}
+
+impl<I> Resettable for Owned<I>
+where
+    I: Resettable,
+    for<'a> I::Item<'a>: Deref,
+    for<'a> <I::Item<'a> as Deref>::Target: ToOwned,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use crate::{LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn windows() {
+        let windows: Vec<Vec<i32>> = (0..4).windows(2).owned().into_iter().collect();
+        assert_eq!(windows, vec![vec![0, 1], vec![1, 2], vec![2, 3]]);
+    }
+
+    #[test]
+    fn strings() {
+        let mut owned = vec!["a", "bc"].into_iter().into_lending().owned().into_iter();
+        assert_eq!(owned.next(), Some("a".to_string()));
+        assert_eq!(owned.next(), Some("bc".to_string()));
+        assert_eq!(owned.next(), None);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::LendingIterator;
+use core::num::NonZeroUsize;
+
+use crate::{ExactSizeLendingIterator, LendingIterator, Resettable};
 
 /// A lending iterator that skips over the first `n` items of `iter`.
 #[derive(Clone, Debug)]
@@ -6,11 +8,13 @@ use crate::LendingIterator;
 pub struct Skip<I> {
     iter: I,
     n: usize,
+    // The `n` to go back to when reset.
+    orig_n: usize,
 }
 
 impl<I> Skip<I> {
     pub(crate) fn new(iter: I, n: usize) -> Skip<I> {
-        Skip { iter, n }
+        Skip { iter, n, orig_n: n }
     }
 }
 
@@ -37,14 +41,82 @@ where
         let upper = upper.map(|x| x.saturating_sub(self.n));
         (lower, upper)
     }
+
+    #[inline]
+    fn advance_by(&mut self, mut n: usize) -> Result<(), NonZeroUsize> {
+        let skip = self.n;
+        let skip_and_advance = skip.saturating_add(n);
+        let remainder = match self.iter.advance_by(skip_and_advance) {
+            Ok(()) => 0,
+            Err(remainder) => remainder.get(),
+        };
+        let advanced = skip_and_advance - remainder;
+        n -= advanced.saturating_sub(skip);
+        self.n = skip.saturating_sub(advanced);
+        // `skip_and_advance` may have saturated.
+        if remainder == 0 && n > 0 {
+            n = match self.iter.advance_by(n) {
+                Ok(()) => 0,
+                Err(remainder) => remainder.get(),
+            };
+        }
+        NonZeroUsize::new(n).map_or(Ok(()), Err)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        if self.n > 0 {
+            let skip = core::mem::take(&mut self.n);
+            let n = if let Some(nth) = skip.checked_add(n) {
+                nth
+            } else {
+                self.iter.nth(skip - 1)?;
+                n
+            };
+            self.iter.nth(n)
+        } else {
+            self.iter.nth(n)
+        }
+    }
+
+    #[inline]
+    fn fold<B, F>(mut self, init: B, f: F) -> B
+    where
+        F: FnMut(B, I::Item<'_>) -> B,
+    {
+        if self.n > 0 && self.iter.nth(self.n - 1).is_none() {
+            return init;
+        }
+        self.iter.fold(init, f)
+    }
+}
+
+impl<I: ExactSizeLendingIterator> ExactSizeLendingIterator for Skip<I> {}
+
+impl<I: Resettable> Resettable for Skip<I> {
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+        self.n = self.orig_n;
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::ToLendingIterator;
+    use crate::{testing, ToLendingIterator};
     #[test]
     fn test() {
         assert_eq!((0..5).into_lending().skip(1).nth(1), (0..5).skip(1).nth(1));
     }
+
+    #[test]
+    fn nth() {
+        for n in 0..7 {
+            testing::assert_nth(|| (0..5).into_lending().skip(n), |x| x);
+        }
+        let mut skip = (0..5).into_lending().skip(2);
+        assert_eq!(skip.advance_by(4), Err(NonZeroUsize::new(1).unwrap()));
+        assert_eq!(skip.next(), None);
+    }
 }
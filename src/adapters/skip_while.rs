@@ -1,4 +1,4 @@
-use crate::LendingIterator;
+use crate::{ByRef, LendingIterator, Resettable};
 use core::fmt;
 
 /// A lending iterator that that rejects elements while `predicate` returns `true`.
@@ -38,21 +38,7 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item<'_>> {
-        if self.flag {
-            return self.iter.next()
-        }
-        loop {
-            // SAFETY: see https://docs.rs/polonius-the-crab/0.3.1/polonius_the_crab/#the-arcanemagic
-            let self_ = unsafe { &mut *(self as *mut Self) };
-            if let Some(item) = self_.iter.next() {
-                if !(self_.predicate)(&item) {
-                    self_.flag = true;
-                    return Some(item);
-                }
-            } else {
-                return None;
-            }
-        }
+        skip_while_next(&mut self.iter, &mut self.predicate, &mut self.flag)
     }
 
     #[inline]
@@ -61,6 +47,76 @@ where
         (0, upper) // can't know a lower bound, due to the predicate
     }
 
-    // TODO: there's a `fold` optimization possible here,
-    // but for some reason the lifetimes don't type check
+    // The bound is spelled with `I::Item` because with `Self::Item` the
+    // lifetimes don't type check.
+    #[inline]
+    fn fold<B, F>(mut self, mut init: B, mut f: F) -> B
+    where
+        F: FnMut(B, I::Item<'_>) -> B,
+    {
+        if !self.flag {
+            match self.next() {
+                Some(item) => init = f(init, item),
+                None => return init,
+            }
+        }
+        self.iter.fold(init, f)
+    }
+}
+
+impl<I, P> LendingIterator for SkipWhile<ByRef<'_, I>, P>
+where
+    I: LendingIterator + ?Sized,
+    P: for<'a> FnMut(&I::Item<'a>) -> bool,
+{
+    type Item<'a> = I::Item<'a> where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        skip_while_next(self.iter.iter, &mut self.predicate, &mut self.flag)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.iter.size_hint();
+        (0, upper)
+    }
+}
+
+#[inline]
+fn skip_while_next<'a, I, P>(iter: &'a mut I, predicate: &mut P, flag: &mut bool) -> Option<I::Item<'a>>
+where
+    I: LendingIterator + ?Sized,
+    P: FnMut(&I::Item<'_>) -> bool,
+{
+    if *flag {
+        return iter.next()
+    }
+    loop {
+        #[cfg(not(feature = "polonius"))]
+        // SAFETY: see https://docs.rs/polonius-the-crab/0.3.1/polonius_the_crab/#the-arcanemagic
+        let iter_ = unsafe { &mut *(iter as *mut I) };
+        #[cfg(feature = "polonius")]
+        let iter_ = &mut *iter;
+        if let Some(item) = iter_.next() {
+            if !predicate(&item) {
+                *flag = true;
+                return Some(item);
+            }
+        } else {
+            return None;
+        }
+    }
+}
+
+impl<I, P> Resettable for SkipWhile<I, P>
+where
+    I: Resettable,
+    P: for<'a> FnMut(&I::Item<'a>) -> bool,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+        self.flag = false;
+    }
 }
@@ -1,4 +1,4 @@
-use crate::LendingIterator;
+use crate::{ExactSizeLendingIterator, LendingIterator, Resettable};
 
 /// A lending iterator for stepping lending iterators by a custom amount.
 ///
@@ -45,4 +45,103 @@ where
             self.iter.nth(self.step)
         }
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // `self.step` is one less than the step, so this can't overflow.
+        let step = self.step + 1;
+        let size = |n: usize| {
+            if self.first_take {
+                if n == 0 {
+                    0
+                } else {
+                    1 + (n - 1) / step
+                }
+            } else {
+                n / step
+            }
+        };
+        let (lower, upper) = self.iter.size_hint();
+        (size(lower), upper.map(size))
+    }
+
+    #[inline]
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item<'_>> {
+        if self.first_take {
+            self.first_take = false;
+            if n == 0 {
+                return self.iter.next();
+            }
+            // Like std, the first element is consumed even if it is `None`.
+            self.iter.next();
+            n -= 1;
+        }
+        // `n + 1` steps of `self.step + 1`, minus one for the element `nth` lends.
+        let step = self.step + 1;
+        let mut n = n + 1;
+        loop {
+            if let Some(m) = n.checked_mul(step) {
+                return self.iter.nth(m - 1);
+            }
+            // Overflow: advance as far as possible and keep going.
+            let div = usize::MAX / step;
+            self.iter.nth(div * step - 1)?;
+            n -= div;
+        }
+    }
+
+    #[inline]
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, I::Item<'_>) -> B,
+    {
+        let mut acc = init;
+        if self.first_take {
+            match self.iter.next() {
+                Some(item) => acc = f(acc, item),
+                None => return acc,
+            }
+        }
+        while let Some(item) = self.iter.nth(self.step) {
+            acc = f(acc, item);
+        }
+        acc
+    }
+}
+
+impl<I: ExactSizeLendingIterator> ExactSizeLendingIterator for StepBy<I> {}
+
+impl<I: Resettable> Resettable for StepBy<I> {
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+        self.first_take = true;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{testing, LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn nth() {
+        for len in 0..8 {
+            for step in 1..4 {
+                let make = || (0..len).into_lending().step_by(step);
+                testing::assert_nth(make, |x| x);
+                testing::assert_size_hint(make);
+            }
+        }
+    }
+
+    #[test]
+    fn nth_overflow() {
+        // `(n + 1) * step` overflows, so the steps are taken in parts.
+        let step = usize::MAX / 2;
+        let mut lending = core::iter::repeat(7).into_lending().step_by(step);
+        let mut std = core::iter::repeat(7).step_by(step);
+        assert_eq!(lending.nth(5), std.nth(5));
+        assert_eq!(lending.nth(2), std.nth(2));
+        assert_eq!((0..10).into_lending().step_by(step).nth(3), None);
+    }
 }
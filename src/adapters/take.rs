@@ -1,4 +1,6 @@
-use crate::LendingIterator;
+use core::num::NonZeroUsize;
+
+use crate::{ExactSizeLendingIterator, LendingIterator, Resettable};
 
 /// A Lending iterator that only lends the first `n` iterations of `iter`.
 #[derive(Clone, Debug)]
@@ -6,6 +8,8 @@ use crate::LendingIterator;
 pub struct Take<I> {
     iter: I,
     n: usize,
+    // The `n` to go back to when reset.
+    orig_n: usize,
 }
 
 impl<I> Take<I>
@@ -13,7 +17,7 @@ where
     I: LendingIterator,
 {
     pub(crate) fn new(iter: I, n: usize) -> Take<I> {
-        Take { iter, n }
+        Take { iter, n, orig_n: n }
     }
 }
 
@@ -33,12 +37,79 @@ where
             None
         }
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.n == 0 {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.iter.size_hint();
+        let lower = lower.min(self.n);
+        let upper = Some(upper.map_or(self.n, |x| x.min(self.n)));
+        (lower, upper)
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let min = self.n.min(n);
+        let rem = match self.iter.advance_by(min) {
+            Ok(()) => 0,
+            Err(rem) => rem.get(),
+        };
+        let advanced = min - rem;
+        self.n -= advanced;
+        NonZeroUsize::new(n - advanced).map_or(Ok(()), Err)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        if self.n > n {
+            self.n -= n + 1;
+            self.iter.nth(n)
+        } else {
+            if self.n > 0 {
+                self.iter.nth(self.n - 1);
+                self.n = 0;
+            }
+            None
+        }
+    }
+
+    #[inline]
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, I::Item<'_>) -> B,
+    {
+        // The underlying lending iterator has at most `n` items left, so it can
+        // fold on its own.
+        if self.iter.size_hint().1.map_or(false, |upper| upper <= self.n) {
+            return self.iter.fold(init, f);
+        }
+        let mut acc = init;
+        for _ in 0..self.n {
+            match self.iter.next() {
+                Some(item) => acc = f(acc, item),
+                None => break,
+            }
+        }
+        acc
+    }
+}
+
+impl<I: ExactSizeLendingIterator> ExactSizeLendingIterator for Take<I> {}
+
+impl<I: Resettable> Resettable for Take<I> {
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+        self.n = self.orig_n;
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::ToLendingIterator;
+    use crate::{testing, ToLendingIterator};
     #[test]
     fn test() {
         assert_eq!(
@@ -49,4 +120,15 @@ mod test {
             5
         );
     }
+
+    #[test]
+    fn nth() {
+        for len in 0..4 {
+            for n in 0..6 {
+                let make = || (0..len).into_lending().take(n);
+                testing::assert_nth(make, |x| x);
+                testing::assert_size_hint(make);
+            }
+        }
+    }
 }
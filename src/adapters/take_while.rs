@@ -1,4 +1,4 @@
-use crate::LendingIterator;
+use crate::{ByRef, LendingIterator, Resettable};
 use core::fmt;
 
 /// A lending iterator that yields items based on a predicate.
@@ -40,25 +40,68 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item<'_>> {
+        take_while_next(&mut self.iter, &mut self.predicate, &mut self.done)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
         if self.done {
-            None
+            (0, Some(0))
         } else {
-            let item = self.iter.next()?;
-            if (self.predicate)(&item) {
-                Some(item)
-            } else {
-                self.done = true;
-                None
-            }
+            (0, self.iter.size_hint().1)
         }
     }
+}
+
+impl<I, P> LendingIterator for TakeWhile<ByRef<'_, I>, P>
+where
+    I: LendingIterator + ?Sized,
+    P: for<'a> FnMut(&I::Item<'a>) -> bool,
+{
+    type Item<'a> = I::Item<'a> where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        take_while_next(self.iter.iter, &mut self.predicate, &mut self.done)
+    }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         if self.done {
             (0, Some(0))
         } else {
-            (0, self.iter.size_hint().1)
+            (0, self.iter.iter.size_hint().1)
+        }
+    }
+}
+
+#[inline]
+fn take_while_next<'a, I, P>(iter: &'a mut I, predicate: &mut P, done: &mut bool) -> Option<I::Item<'a>>
+where
+    I: LendingIterator + ?Sized,
+    P: FnMut(&I::Item<'_>) -> bool,
+{
+    if *done {
+        None
+    } else {
+        let item = iter.next()?;
+        if predicate(&item) {
+            Some(item)
+        } else {
+            *done = true;
+            None
         }
     }
 }
+
+impl<I, P> Resettable for TakeWhile<I, P>
+where
+    I: Resettable,
+    P: for<'a> FnMut(&I::Item<'a>) -> bool,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+        self.done = false;
+    }
+}
@@ -1,4 +1,4 @@
-use crate::LendingIterator;
+use crate::{ExactSizeLendingIterator, LendingIterator, Resettable};
 
 /// A lending iterator that iterates two other lending iterators simultaneously.
 ///
@@ -35,4 +35,34 @@ where
         let b = self.b.next()?;
         Some((a, b))
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        (a_lower.min(b_lower), upper)
+    }
+}
+
+impl<A, B> ExactSizeLendingIterator for Zip<A, B>
+where
+    A: ExactSizeLendingIterator,
+    B: ExactSizeLendingIterator,
+{
+}
+
+impl<A, B> Resettable for Zip<A, B>
+where
+    A: Resettable,
+    B: Resettable,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.a.reset();
+        self.b.reset();
+    }
 }
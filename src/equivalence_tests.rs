@@ -0,0 +1,402 @@
+//! Checks every adapter against its `std::iter` counterpart on random inputs,
+//! with both fused and non-fused sources.
+
+use crate::{
+    testing::Delay,
+    test_util::{assert_equivalent, assert_fold_equivalent, Rng},
+    Either, LendingIterator, ToLendingIterator,
+};
+
+const CASES: u64 = 200;
+const MAX_LEN: usize = 12;
+
+/// Applies the same adapters to a random lending iterator and the equivalent
+/// [`Iterator`], with `$rng` in the same state for both, and asserts that they behave
+/// the same, both when driven by `next` and `nth` and when folded.
+macro_rules! equivalent {
+    (|$it:ident, $rng:ident| $adapt:expr) => {
+        equivalent!(|$it, $rng| $adapt, $adapt)
+    };
+    (|$it:ident, $rng:ident| $lending:expr, $std:expr) => {
+        for seed in 0..CASES {
+            let mut rng = Rng::new(seed);
+            let data = rng.vec(MAX_LEN);
+            let delay = rng.below(3);
+            let ops = Rng::new(rng.next_u64());
+            let source = || data.clone().into_iter();
+
+            // Folding `cycle` never ends.
+            let finite = {
+                #[allow(unused_mut, unused_variables)]
+                let mut $rng = rng.clone();
+                let $it = source();
+                $std.size_hint().1.is_some()
+            };
+            equivalent!(@check |$it, $rng| $lending, $std;
+                source().into_lending(), source(); &rng, &ops, finite);
+            equivalent!(@check |$it, $rng| $lending, $std;
+                Delay::new(delay, source().into_lending()), Delay::new(delay, source());
+                &rng, &ops, finite);
+        }
+    };
+    (@check |$it:ident, $rng:ident| $lending:expr, $std:expr;
+        $lending_source:expr, $std_source:expr; $params:expr, $ops:expr, $finite:expr) => {
+        #[allow(unused_mut, unused_variables)]
+        let lending = || {
+            let mut $rng = Rng::clone($params);
+            let $it = $lending_source;
+            $lending
+        };
+        #[allow(unused_mut, unused_variables)]
+        let std = || {
+            let mut $rng = Rng::clone($params);
+            let $it = $std_source;
+            $std
+        };
+        assert_equivalent(lending(), std(), |x| x, Rng::clone($ops));
+        if $finite {
+            assert_fold_equivalent(lending(), std(), |x| x);
+        }
+    };
+}
+
+fn small(rng: &mut Rng) -> u8 {
+    u8::try_from(rng.below(10)).unwrap()
+}
+
+#[test]
+fn identity() {
+    equivalent!(|it, rng| it);
+}
+
+#[test]
+fn step_by() {
+    equivalent!(|it, rng| it.step_by(rng.below(3) + 1));
+}
+
+#[test]
+fn take() {
+    equivalent!(|it, rng| it.take(rng.below(MAX_LEN)));
+}
+
+#[test]
+fn skip() {
+    equivalent!(|it, rng| it.skip(rng.below(MAX_LEN)));
+}
+
+#[test]
+fn take_while() {
+    equivalent!(|it, rng| {
+        let limit = small(&mut rng);
+        it.take_while(move |x: &u8| *x < limit)
+    });
+}
+
+#[test]
+fn skip_while() {
+    equivalent!(|it, rng| {
+        let limit = small(&mut rng);
+        it.skip_while(move |x: &u8| *x < limit)
+    });
+}
+
+#[test]
+fn filter() {
+    equivalent!(|it, rng| {
+        let m = small(&mut rng) + 1;
+        it.filter(move |x: &u8| x % m == 0)
+    });
+}
+
+#[test]
+fn filter_map() {
+    equivalent!(|it, rng| {
+        let m = small(&mut rng) + 1;
+        it.filter_map(move |x: u8| (x % m == 0).then_some(x / m))
+    });
+}
+
+#[test]
+fn map() {
+    equivalent!(|it, rng| it.map(|x: u8| u16::from(x) * 3));
+}
+
+#[test]
+fn enumerate() {
+    equivalent!(|it, rng| it.enumerate());
+}
+
+#[test]
+fn chain() {
+    equivalent!(
+        |it, rng| it.chain((0..small(&mut rng)).into_lending()),
+        it.chain(0..small(&mut rng))
+    );
+}
+
+#[test]
+fn chain_same() {
+    equivalent!(
+        |it, rng| {
+            let second = it.clone();
+            it.chain_same(second)
+        },
+        {
+            let second = it.clone();
+            it.chain(second)
+        }
+    );
+}
+
+#[test]
+fn zip() {
+    equivalent!(
+        |it, rng| it.zip((0..small(&mut rng)).into_lending()),
+        it.zip(0..small(&mut rng))
+    );
+    equivalent!(
+        |it, rng| (0..small(&mut rng)).into_lending().zip(it),
+        (0..small(&mut rng)).zip(it)
+    );
+}
+
+#[test]
+fn by_ref() {
+    equivalent!(|it, rng| {
+        let mut it = it;
+        it.by_ref().take(rng.below(MAX_LEN)).count();
+        it
+    });
+}
+
+#[test]
+fn cycle() {
+    equivalent!(|it, rng| it.take(rng.below(4)).cycle());
+}
+
+#[test]
+fn either() {
+    equivalent!(
+        |it, rng| {
+            let n = rng.below(MAX_LEN);
+            if rng.below(2) == 0 {
+                Either::Left(it.take(n))
+            } else {
+                Either::Right(it.skip(n))
+            }
+        },
+        {
+            let n = rng.below(MAX_LEN);
+            if rng.below(2) == 0 {
+                Box::new(it.take(n)) as Box<dyn Iterator<Item = u8>>
+            } else {
+                Box::new(it.skip(n))
+            }
+        }
+    );
+}
+
+#[test]
+fn intersperse() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let data = rng.vec(MAX_LEN);
+        let separator = small(&mut rng) + 10;
+        let ops = Rng::new(rng.next_u64());
+        let refs = || data.clone().into_iter().lend_refs();
+        let interspersed: Vec<u8> = data
+            .iter()
+            .enumerate()
+            .flat_map(|(i, x)| (i > 0).then_some(separator).into_iter().chain([*x]))
+            .collect();
+        let std = || interspersed.clone().into_iter();
+
+        assert_equivalent(refs().intersperse(separator), std(), |x: &u8| *x, ops.clone());
+        assert_equivalent(refs().intersperse_with(move || separator), std(), |x: &u8| *x, ops);
+        assert_fold_equivalent(refs().intersperse(separator), std(), |x: &u8| *x);
+        assert_fold_equivalent(refs().intersperse_with(move || separator), std(), |x: &u8| *x);
+    }
+}
+
+#[test]
+fn lend_refs() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let data = rng.vec(MAX_LEN);
+        let ops = Rng::new(rng.next_u64());
+
+        assert_equivalent(
+            data.clone().into_iter().lend_refs(),
+            data.clone().into_iter(),
+            |x: &u8| *x,
+            ops.clone(),
+        );
+        assert_equivalent(
+            data.clone().into_iter().lend_refs_mut(),
+            data.clone().into_iter(),
+            |x: &mut u8| *x,
+            ops,
+        );
+        assert_fold_equivalent(data.clone().into_iter().lend_refs(), data.clone().into_iter(), |x: &u8| *x);
+        assert_fold_equivalent(
+            data.clone().into_iter().lend_refs_mut(),
+            data.clone().into_iter(),
+            |x: &mut u8| *x,
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn windows() {
+    use crate::ToLendingSliceWindows;
+
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let data = rng.vec(MAX_LEN);
+        let size = rng.below(4) + 1;
+        let ops = Rng::new(rng.next_u64());
+
+        assert_equivalent(
+            data.clone().into_iter().windows(size),
+            data[..].windows(size).map(<[u8]>::to_vec),
+            |w: &[u8]| w.to_vec(),
+            ops.clone(),
+        );
+        assert_equivalent(
+            data.clone().into_iter().windows_mut(size),
+            data[..].windows(size).map(<[u8]>::to_vec),
+            |w: &mut [u8]| w.to_vec(),
+            ops.clone(),
+        );
+        assert_equivalent(
+            data.clone().into_iter().array_windows::<3>(),
+            data[..].windows(3).map(|w| <[u8; 3]>::try_from(w).unwrap()),
+            |w: &[u8; 3]| *w,
+            ops.clone(),
+        );
+        assert_equivalent(
+            data.clone().into_iter().array_windows_mut::<3>(),
+            data[..].windows(3).map(|w| <[u8; 3]>::try_from(w).unwrap()),
+            |w: &mut [u8; 3]| *w,
+            ops,
+        );
+
+        let std = || data[..].windows(size).map(<[u8]>::to_vec);
+        let arrays = || data[..].windows(3).map(|w| <[u8; 3]>::try_from(w).unwrap());
+        let source = || data.clone().into_iter();
+        assert_fold_equivalent(source().windows(size), std(), |w: &[u8]| w.to_vec());
+        assert_fold_equivalent(source().windows_mut(size), std(), |w: &mut [u8]| w.to_vec());
+        assert_fold_equivalent(source().array_windows::<3>(), arrays(), |w: &[u8; 3]| *w);
+        assert_fold_equivalent(source().array_windows_mut::<3>(), arrays(), |w: &mut [u8; 3]| *w);
+        assert_fold_equivalent(data.clone().lend_windows_mut(size), std(), |w: &mut [u8]| w.to_vec());
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn reset_cycle() {
+    use crate::ToLendingSliceWindows;
+
+    // Unlike `Cycle`, `ResetCycle` can't tell an exhausted lending iterator from an
+    // empty one, so only the items are compared.
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let data = rng.vec(MAX_LEN);
+        let size = rng.below(4) + 1;
+        let mut lending = data.clone().lend_windows_mut(size).reset_cycle();
+        let mut std = data[..].windows(size).cycle();
+        for _ in 0..50 {
+            let n = rng.below(3);
+            assert_eq!(lending.nth(n).map(|w| w.to_vec()), std.nth(n).map(<[u8]>::to_vec));
+        }
+        // Folding only ends when there are no windows at all.
+        if data.len() < size {
+            let lending = data.clone().lend_windows_mut(size).reset_cycle();
+            let std = data[..].windows(size).map(<[u8]>::to_vec);
+            assert_fold_equivalent(lending, std, |w: &mut [u8]| w.to_vec());
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn split_mut() {
+    use crate::{ToLendingSplits, ToLendingStrSplits};
+
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let data = rng.vec(MAX_LEN);
+        let m = small(&mut rng) + 1;
+        let delimiter = move |x: &u8| x % m == 0;
+        let token = |t: &mut [u8]| t.to_vec();
+        assert_fold_equivalent(
+            data.clone().lend_split_mut(delimiter),
+            data.split(delimiter).map(<[u8]>::to_vec),
+            token,
+        );
+        assert_fold_equivalent(
+            data.clone().lend_split_inclusive_mut(delimiter),
+            data.split_inclusive(delimiter).map(<[u8]>::to_vec),
+            token,
+        );
+
+        let text: String = data
+            .iter()
+            .map(|x| if delimiter(x) { '·' } else { char::from(b'a' + x) })
+            .collect();
+        let dot = |c: char| c == '·';
+        let token = |t: &mut str| t.to_owned();
+        assert_fold_equivalent(String::from(&text).lend_split_mut(dot), text.split('·').map(String::from), token);
+        assert_fold_equivalent(
+            String::from(&text).lend_split_inclusive_mut(dot),
+            text.split_inclusive('·').map(String::from),
+            token,
+        );
+        assert_fold_equivalent(
+            String::from(&text).lend_split_terminator_mut(dot),
+            text.split_terminator('·').map(String::from),
+            token,
+        );
+    }
+}
+
+#[test]
+fn into_iter_owned() {
+    equivalent!(|it, rng| it.into_iter_owned().into_lending(), it);
+}
+
+#[test]
+fn cloned_copied_owned() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let data = rng.vec(MAX_LEN);
+        let ops = Rng::new(rng.next_u64());
+        let refs = || data.clone().into_iter().lend_refs();
+
+        assert_equivalent(
+            refs().cloned().into_iter().into_lending(),
+            data.clone().into_iter(),
+            |x| x,
+            ops.clone(),
+        );
+        assert_equivalent(
+            refs().copied().into_iter().into_lending(),
+            data.clone().into_iter(),
+            |x| x,
+            ops.clone(),
+        );
+        #[cfg(feature = "alloc")]
+        assert_equivalent(
+            refs().owned().into_iter().into_lending(),
+            data.clone().into_iter(),
+            |x| x,
+            ops,
+        );
+
+        assert_fold_equivalent(refs().cloned(), data.clone().into_iter(), |x| x);
+        assert_fold_equivalent(refs().copied(), data.clone().into_iter(), |x| x);
+        #[cfg(feature = "alloc")]
+        assert_fold_equivalent(refs().owned(), data.clone().into_iter(), |x| x);
+    }
+}
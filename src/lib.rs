@@ -6,7 +6,7 @@
 //! Basically any method that needs to look at more than one element at once isn't possible, or needs to be modified.
 //!
 //! Some `LendingIterator` methods *may* return something that can act as an `Iterator`.
-//! For example `cloned`, or `map`, when the function passed to it
+//! For example `cloned`, `copied`, `owned`, or `map`, when the function passed to it
 //! returns a value that isn't tied to the lifetime of its input.
 //! In these cases, my design choice was to conditionally implement `IntoIterator` for the adapter.
 //!
@@ -14,10 +14,40 @@
 //! that allows turning them into lending iterators (over windows of elements).
 //! There may be more methods added to this trait in the future.
 //!
+//! # Features
+//!
+//! The crate is `no_std`. The lending iterator traits and the adapters that don't
+//! need to allocate are always available, as are splitting `&mut [T]` and `&mut str`
+//! buffers into tokens with [`ToLendingSplits`] and [`ToLendingStrSplits`], and lending resettable windows of
+//! `&mut [T]` with [`ToLendingSliceWindows`]. Other functionality is behind cargo features:
+//!
+//! - `alloc`: sources that buffer items, like [`windows`](crate::ToLendingIterator::windows)
+//!   and [`windows_mut`](crate::ToLendingIterator::windows_mut),
+//!   lending the entries of a `BTreeMap` with [`ToLendingEntries`],
+//!   editing a `Vec`, `VecDeque` or `LinkedList` while iterating with [`ToLendingCursors`],
+//!   splitting and lending windows of owned buffers like `Vec<T>` and `String`,
+//!   and forwarding implementations for `Box`.
+//! - `std` (enabled by default): everything that needs the standard library, like
+//!   [`par_map_owned`](crate::LendingIterator::par_map_owned) and lending the entries
+//!   of a `HashMap`. Implies `alloc`.
+//! - `testing`: the `testing` module, with checkers for implementors of `LendingIterator`
+//!   and adversarial lending iterators for testing adapters.
+//!
+//! For bare metal targets, disable the default features and enable `alloc` if there is an allocator.
+//!
+//! `find`, [`Filter`], [`FilterMap`] and [`SkipWhile`] conditionally return an item
+//! borrowed from the underlying lending iterator, which the current borrow checker rejects.
+//! By default they work around this with a small amount of `unsafe` code
+//! (see [polonius-the-crab](https://docs.rs/polonius-the-crab/0.3.1/polonius_the_crab/#the-arcanemagic)).
+//! Enabling the `polonius` feature replaces that with the equivalent safe code,
+//! and forbids `unsafe` in the crate. That code only compiles with the next generation
+//! borrow checker, so it requires a nightly compiler and `RUSTFLAGS="-Zpolonius"`.
+//!
 //! # Examples
 //!
 //! Using [`windows`](crate::ToLendingIterator::windows) on a range, filtering it and chaining it:
 //! ```
+//! # #[cfg(feature = "alloc")] {
 //! use gat_lending_iterator::{LendingIterator, ToLendingIterator};
 //!
 //! (0..5)
@@ -25,6 +55,7 @@
 //!     .filter(|x| x[0] % 2 == 0)
 //!     .chain((0..6).windows(2))
 //!     .for_each(|x| println!("{x:?}"));
+//! # }
 //! ```
 //!
 //! Prints:
@@ -40,6 +71,7 @@
 //!
 //! Using [`windows_mut`](crate::ToLendingIterator::windows_mut) on a range, mutating it and mapping it:
 //! ```
+//! # #[cfg(feature = "alloc")] {
 //! use gat_lending_iterator::{LendingIterator, ToLendingIterator};
 //!
 //! for sum in (0..7).windows_mut(2).map(|slice: &mut [usize]| {
@@ -48,6 +80,7 @@
 //! }) {
 //!     println!("{sum}");
 //! }
+//! # }
 //! ```
 //!
 //! Prints:
@@ -62,6 +95,7 @@
 //!
 //! Using [`windows`](crate::ToLendingIterator::windows) on a range, and mapping it:
 //! ```
+//! # #[cfg(feature = "alloc")] {
 //! use gat_lending_iterator::{LendingIterator, ToLendingIterator};
 //! fn second(slice: &[usize]) -> &usize {
 //!     &slice[1]
@@ -70,6 +104,7 @@
 //! for n in (0..5).windows(3).map(second).cloned() {
 //!     println!("{n}");
 //! }
+//! # }
 //! ```
 //!
 //! Prints:
@@ -79,17 +114,31 @@
 //! 3
 //! ```
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![deny(missing_docs)]
+#![cfg_attr(feature = "polonius", forbid(unsafe_code))]
 #![warn(clippy::pedantic)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod adapters;
+pub mod stream;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 mod to_lending;
 mod traits;
 pub use self::adapters::*;
+pub use self::stream::LendingStream;
 pub use self::to_lending::*;
 pub use self::traits::*;
 
 #[cfg(test)]
+mod equivalence_tests;
+#[cfg(test)]
+mod test_util;
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
 
@@ -0,0 +1,49 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::stream::LendingStream;
+
+/// A lending stream that yields the current count and the element during iteration.
+///
+/// This `struct` is created by the [`enumerate`] method on [`LendingStream`]. See
+/// its documentation for more.
+///
+/// [`LendingStream`]: crate::stream::LendingStream
+/// [`enumerate`]: crate::stream::LendingStream::enumerate
+#[derive(Clone, Debug)]
+#[must_use = "streams are lazy and do nothing unless polled"]
+pub struct Enumerate<S> {
+    count: usize,
+    stream: S,
+}
+
+impl<S> Enumerate<S> {
+    pub(crate) fn new(stream: S) -> Self {
+        Enumerate { stream, count: 0 }
+    }
+}
+
+impl<S> LendingStream for Enumerate<S>
+where
+    S: LendingStream + Unpin,
+{
+    type Item<'a> = (usize, S::Item<'a>) where Self: 'a;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item<'_>>> {
+        let this = self.get_mut();
+        Pin::new(&mut this.stream).poll_next(cx).map(|item| {
+            let item = item?;
+            let count = this.count;
+            this.count += 1;
+            Some((count, item))
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -0,0 +1,72 @@
+use core::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::stream::LendingStream;
+
+/// A lending stream that filters the elements of `stream` with `predicate`.
+///
+/// This `struct` is created by the [`filter`] method on [`LendingStream`]. See
+/// its documentation for more.
+///
+/// [`LendingStream`]: crate::stream::LendingStream
+/// [`filter`]: crate::stream::LendingStream::filter
+#[derive(Clone)]
+#[must_use = "streams are lazy and do nothing unless polled"]
+pub struct Filter<S, P> {
+    stream: S,
+    predicate: P,
+}
+
+impl<S, P> Filter<S, P> {
+    pub(crate) fn new(stream: S, predicate: P) -> Self {
+        Self { stream, predicate }
+    }
+}
+
+impl<S: fmt::Debug, P> fmt::Debug for Filter<S, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Filter").field("stream", &self.stream).finish_non_exhaustive()
+    }
+}
+
+// The predicate is never pinned.
+impl<S: Unpin, P> Unpin for Filter<S, P> {}
+
+impl<S, P> LendingStream for Filter<S, P>
+where
+    S: LendingStream + Unpin,
+    P: for<'a> FnMut(&S::Item<'a>) -> bool,
+{
+    type Item<'a> = S::Item<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item<'_>>> {
+        let this = self.get_mut();
+        loop {
+            #[cfg(not(feature = "polonius"))]
+            // SAFETY: see https://docs.rs/polonius-the-crab/0.3.1/polonius_the_crab/#the-arcanemagic
+            let stream_ = unsafe { &mut *core::ptr::addr_of_mut!(this.stream) };
+            #[cfg(feature = "polonius")]
+            let stream_ = &mut this.stream;
+            match Pin::new(stream_).poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if (this.predicate)(&item) {
+                        return Poll::Ready(Some(item));
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
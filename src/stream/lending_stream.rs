@@ -0,0 +1,145 @@
+use core::{
+    ops::DerefMut,
+    pin::Pin,
+    task::{Context, Poll},
+};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use crate::{
+    stream::{Enumerate, Filter, Map, Next, Take},
+    SingleArgFnMut,
+};
+
+/// Like [`LendingIterator`](crate::LendingIterator), but asynchronous:
+/// items may borrow from the stream, and may not be ready yet.
+pub trait LendingStream {
+    /// The type of the elements being streamed.
+    type Item<'a>
+    where
+        Self: 'a;
+
+    /// Attempts to pull out the next value of this stream, registering the current task
+    /// for wakeup if the value is not yet available, and returning `None` if the stream
+    /// is exhausted.
+    ///
+    /// The returned item borrows from the stream, so it has to be dropped before
+    /// the stream can be polled again.
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item<'_>>>;
+
+    /// Returns the bounds on the remaining length of the stream.
+    ///
+    /// See [`Iterator::size_hint`].
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+
+    /// Creates a future that resolves to the next item in the stream.
+    ///
+    /// This is the asynchronous equivalent of [`LendingIterator::next`](crate::LendingIterator::next),
+    /// to be used as `stream.next().await`.
+    #[inline]
+    fn next(&mut self) -> Next<'_, Self>
+    where
+        Self: Unpin,
+    {
+        Next::new(self)
+    }
+
+    /// Takes a closure and creates a lending stream which calls that closure on each
+    /// element.
+    ///
+    /// See [`LendingIterator::map`](crate::LendingIterator::map).
+    #[inline]
+    fn map<F>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: for<'a> SingleArgFnMut<Self::Item<'a>>,
+    {
+        Map::new(self, f)
+    }
+
+    /// Creates a lending stream which uses a closure to determine if an element
+    /// should be yielded.
+    ///
+    /// See [`LendingIterator::filter`](crate::LendingIterator::filter).
+    #[inline]
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: for<'a> FnMut(&Self::Item<'a>) -> bool,
+    {
+        Filter::new(self, predicate)
+    }
+
+    /// Creates a lending stream that lends the first `n` elements, or fewer
+    /// if the underlying stream ends sooner.
+    ///
+    /// See [`LendingIterator::take`](crate::LendingIterator::take).
+    #[inline]
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take::new(self, n)
+    }
+
+    /// Creates a lending stream which gives the current iteration count as well as the next value.
+    ///
+    /// See [`LendingIterator::enumerate`](crate::LendingIterator::enumerate).
+    #[inline]
+    fn enumerate(self) -> Enumerate<Self>
+    where
+        Self: Sized,
+    {
+        Enumerate::new(self)
+    }
+}
+
+impl<S: LendingStream + Unpin + ?Sized> LendingStream for &mut S {
+    type Item<'a> = S::Item<'a> where Self: 'a;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item<'_>>> {
+        Pin::new(&mut **self.get_mut()).poll_next(cx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: LendingStream + Unpin + ?Sized> LendingStream for Box<S> {
+    type Item<'a> = S::Item<'a> where Self: 'a;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item<'_>>> {
+        Pin::new(&mut **self.get_mut()).poll_next(cx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
+
+impl<P> LendingStream for Pin<P>
+where
+    P: DerefMut + Unpin,
+    P::Target: LendingStream,
+{
+    type Item<'a> = <P::Target as LendingStream>::Item<'a> where Self: 'a;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item<'_>>> {
+        self.get_mut().as_mut().poll_next(cx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
@@ -0,0 +1,59 @@
+use core::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{stream::LendingStream, SingleArgFnMut, SingleArgFnOnce};
+
+/// A lending stream that maps the elements of `stream` with `f`.
+///
+/// This `struct` is created by the [`map`] method on [`LendingStream`]. See
+/// its documentation for more.
+///
+/// [`LendingStream`]: crate::stream::LendingStream
+/// [`map`]: crate::stream::LendingStream::map
+#[derive(Clone)]
+#[must_use = "streams are lazy and do nothing unless polled"]
+pub struct Map<S, F> {
+    stream: S,
+    f: F,
+}
+
+impl<S, F> Map<S, F> {
+    pub(crate) fn new(stream: S, f: F) -> Self {
+        Self { stream, f }
+    }
+}
+
+impl<S: fmt::Debug, F> fmt::Debug for Map<S, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Map").field("stream", &self.stream).finish_non_exhaustive()
+    }
+}
+
+// The closure is never pinned.
+impl<S: Unpin, F> Unpin for Map<S, F> {}
+
+impl<S, F> LendingStream for Map<S, F>
+where
+    S: LendingStream + Unpin,
+    F: for<'a> SingleArgFnMut<S::Item<'a>>,
+{
+    type Item<'a> = <F as SingleArgFnOnce<S::Item<'a>>>::Output
+        where
+            Self: 'a;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item<'_>>> {
+        let this = self.get_mut();
+        Pin::new(&mut this.stream)
+            .poll_next(cx)
+            .map(|item| item.map(&mut this.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
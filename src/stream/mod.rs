@@ -0,0 +1,145 @@
+//! Asynchronous lending iterators.
+//!
+//! [`LendingStream`] is to [`LendingIterator`](crate::LendingIterator) what a `Stream`
+//! is to an [`Iterator`]: each item may borrow from the stream, and producing the next item
+//! may have to wait. This is useful for example for reading framed messages from a socket
+//! into a buffer that is reused for every frame.
+//!
+//! The adapters in this module require the underlying stream to be [`Unpin`].
+//! Streams that aren't can be pinned first, with [`Box::pin`] or [`core::pin::pin!`],
+//! since `Pin<P>` is a `LendingStream` as well.
+//!
+//! [`Box::pin`]: https://doc.rust-lang.org/std/boxed/struct.Box.html#method.pin
+
+mod enumerate;
+mod filter;
+mod lending_stream;
+mod map;
+mod next;
+mod take;
+pub use self::enumerate::Enumerate;
+pub use self::filter::Filter;
+pub use self::lending_stream::LendingStream;
+pub use self::map::Map;
+pub use self::next::Next;
+pub use self::take::Take;
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+    use core::{
+        future::Future,
+        pin::Pin,
+        task::{Context, Poll, Waker},
+    };
+    use std::{sync::Arc, task::Wake};
+
+    struct Noop;
+
+    impl Wake for Noop {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let mut fut = Box::pin(fut);
+        let waker = Waker::from(Arc::new(Noop));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    // Reads newline separated frames into a reused buffer,
+    // and isn't ready every other time it's polled.
+    struct Frames {
+        input: &'static [u8],
+        buf: Vec<u8>,
+        ready: bool,
+    }
+
+    fn frames(input: &'static [u8]) -> Frames {
+        Frames {
+            input,
+            buf: Vec::new(),
+            ready: false,
+        }
+    }
+
+    impl LendingStream for Frames {
+        type Item<'a> = &'a [u8] where Self: 'a;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item<'_>>> {
+            let this = self.get_mut();
+            this.ready = !this.ready;
+            if !this.ready {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            if this.input.is_empty() {
+                return Poll::Ready(None);
+            }
+            let end = this.input.iter().position(|&b| b == b'\n').unwrap_or(this.input.len());
+            this.buf.clear();
+            this.buf.extend_from_slice(&this.input[..end]);
+            this.input = this.input.get(end + 1..).unwrap_or_default();
+            Poll::Ready(Some(&this.buf))
+        }
+    }
+
+    fn len(frame: &[u8]) -> usize {
+        frame.len()
+    }
+
+    #[test]
+    fn next() {
+        let lens = block_on(async {
+            let mut frames = frames(b"a\nbc\n\ndef");
+            let mut lens = Vec::new();
+            while let Some(frame) = frames.next().await {
+                lens.push(frame.len());
+            }
+            lens
+        });
+        assert_eq!(lens, vec![1, 2, 0, 3]);
+    }
+
+    #[test]
+    fn adapters() {
+        let collected = block_on(async {
+            let mut frames = frames(b"a\nbc\n\ndef\ngh")
+                .filter(|frame| !frame.is_empty())
+                .enumerate()
+                .take(3);
+            let mut collected = Vec::new();
+            while let Some((i, frame)) = frames.next().await {
+                collected.push((i, frame.to_vec()));
+            }
+            collected
+        });
+        assert_eq!(
+            collected,
+            vec![(0, b"a".to_vec()), (1, b"bc".to_vec()), (2, b"def".to_vec())]
+        );
+
+        let lens = block_on(async {
+            let mut lens = frames(b"a\nbc").map(len);
+            let mut collected = Vec::new();
+            while let Some(len) = lens.next().await {
+                collected.push(len);
+            }
+            collected
+        });
+        assert_eq!(lens, vec![1, 2]);
+    }
+
+    #[test]
+    fn pinned() {
+        let first = block_on(async {
+            let mut frames = Box::pin(frames(b"abc"));
+            frames.next().await.map(<[u8]>::to_vec)
+        });
+        assert_eq!(first, Some(b"abc".to_vec()));
+    }
+}
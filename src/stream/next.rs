@@ -0,0 +1,61 @@
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::stream::LendingStream;
+
+/// A future that resolves to the next item of a lending stream.
+///
+/// This `struct` is created by the [`next`] method on [`LendingStream`]. See
+/// its documentation for more.
+///
+/// # Panics
+///
+/// Polling the future after it completed panics.
+///
+/// [`LendingStream`]: crate::stream::LendingStream
+/// [`next`]: crate::stream::LendingStream::next
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Next<'a, S: ?Sized> {
+    stream: Option<&'a mut S>,
+}
+
+impl<'a, S: ?Sized> Next<'a, S> {
+    pub(crate) fn new(stream: &'a mut S) -> Self {
+        Self {
+            stream: Some(stream),
+        }
+    }
+}
+
+impl<S: fmt::Debug + ?Sized> fmt::Debug for Next<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Next").field("stream", &self.stream).finish()
+    }
+}
+
+impl<'a, S> Future for Next<'a, S>
+where
+    S: LendingStream + Unpin + ?Sized,
+{
+    type Output = Option<S::Item<'a>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let stream = self.stream.take().expect("`Next` polled after completion");
+        #[cfg(not(feature = "polonius"))]
+        // SAFETY: see https://docs.rs/polonius-the-crab/0.3.1/polonius_the_crab/#the-arcanemagic
+        let stream_ = unsafe { &mut *(stream as *mut S) };
+        #[cfg(feature = "polonius")]
+        let stream_ = &mut *stream;
+        match Pin::new(stream_).poll_next(cx) {
+            Poll::Ready(item) => Poll::Ready(item),
+            Poll::Pending => {
+                self.stream = Some(stream);
+                Poll::Pending
+            }
+        }
+    }
+}
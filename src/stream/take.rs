@@ -0,0 +1,57 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::stream::LendingStream;
+
+/// A lending stream that only lends the first `n` items of `stream`.
+///
+/// This `struct` is created by the [`take`] method on [`LendingStream`]. See
+/// its documentation for more.
+///
+/// [`LendingStream`]: crate::stream::LendingStream
+/// [`take`]: crate::stream::LendingStream::take
+#[derive(Clone, Debug)]
+#[must_use = "streams are lazy and do nothing unless polled"]
+pub struct Take<S> {
+    stream: S,
+    n: usize,
+}
+
+impl<S> Take<S> {
+    pub(crate) fn new(stream: S, n: usize) -> Self {
+        Self { stream, n }
+    }
+}
+
+impl<S> LendingStream for Take<S>
+where
+    S: LendingStream + Unpin,
+{
+    type Item<'a> = S::Item<'a> where Self: 'a;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item<'_>>> {
+        let this = self.get_mut();
+        if this.n == 0 {
+            return Poll::Ready(None);
+        }
+        let item = Pin::new(&mut this.stream).poll_next(cx);
+        if let Poll::Ready(Some(_)) = item {
+            this.n -= 1;
+        }
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.n == 0 {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.min(self.n);
+        let upper = Some(upper.map_or(self.n, |x| x.min(self.n)));
+        (lower, upper)
+    }
+}
@@ -0,0 +1,83 @@
+//! Helpers shared by the tests of the whole crate.
+
+use core::fmt::Debug;
+
+use crate::LendingIterator;
+
+/// A xorshift pseudo random number generator, so tests are reproducible
+/// without any dependencies.
+#[derive(Clone, Debug)]
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Self {
+        // Zero is a fixed point of xorshift.
+        Rng(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a number in `0..n`.
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        usize::try_from(self.next_u64() % n as u64).unwrap()
+    }
+
+    /// Returns up to `max_len` small numbers.
+    pub(crate) fn vec(&mut self, max_len: usize) -> Vec<u8> {
+        let len = self.below(max_len + 1);
+        (0..len)
+            .map(|_| u8::try_from(self.below(10)).unwrap())
+            .collect()
+    }
+}
+
+/// Drives `lending` and `std` with the same random sequence of `next` and `nth` calls,
+/// asserting that they return the same items and size hints, including after
+/// the first `None`.
+pub(crate) fn assert_equivalent<L, I, F>(mut lending: L, mut std: I, mut project: F, mut rng: Rng)
+where
+    L: LendingIterator,
+    I: Iterator,
+    I::Item: PartialEq + Debug,
+    F: FnMut(L::Item<'_>) -> I::Item,
+{
+    let mut nones = 0;
+    // Bounded so that infinite iterators like `cycle` terminate.
+    for step in 0..100 {
+        let context = format!("step {step} of {rng:?}");
+        assert_eq!(lending.size_hint(), std.size_hint(), "size_hint before {context}");
+        let (lending_item, std_item) = if rng.below(4) == 0 {
+            let n = rng.below(3);
+            (lending.nth(n).map(&mut project), std.nth(n))
+        } else {
+            (lending.next().map(&mut project), std.next())
+        };
+        assert_eq!(lending_item, std_item, "item at {context}");
+        if std_item.is_none() {
+            nones += 1;
+            if nones == 4 {
+                break;
+            }
+        }
+    }
+}
+
+/// Asserts that folding `lending` and `std` visits the same items.
+pub(crate) fn assert_fold_equivalent<L, I, F>(lending: L, std: I, mut project: F)
+where
+    L: LendingIterator,
+    I: Iterator,
+    I::Item: PartialEq + Debug,
+    F: FnMut(L::Item<'_>) -> I::Item,
+{
+    let folded = lending.fold(Vec::new(), |mut items, item| {
+        items.push(project(item));
+        items
+    });
+    assert_eq!(folded, std.collect::<Vec<_>>(), "fold");
+}
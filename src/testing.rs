@@ -0,0 +1,430 @@
+//! Checkers for implementors of [`LendingIterator`], and adversarial lending
+//! iterators to test adapters with.
+//!
+//! The checkers panic with a descriptive message when a lending iterator breaks
+//! the contract of one of its methods. They take a [`Make`] that creates the
+//! lending iterator, so they can compare fresh instances with each other, which
+//! must lend the same items. The lending iterators must be finite.
+//!
+//! Checkers that compare items take a function projecting them to an owned value.
+//! Like other functions over items that don't borrow from them, this requires the
+//! lending iterator to be `'static`.
+//!
+//! Requires the `testing` feature.
+//!
+//! ```
+//! use gat_lending_iterator::{testing, LendingIterator, ToLendingIterator};
+//!
+//! let make = || (0..10).into_lending().step_by(3).enumerate();
+//! testing::assert_size_hint(make);
+//! testing::assert_fused(make);
+//! testing::assert_nth(make, |x| x);
+//! testing::assert_exact_size(make);
+//! ```
+//!
+//! Lending iterators that borrow their state, like the cursors of a `Vec`, are
+//! made with [`Borrowing`] from a fresh state each time:
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use gat_lending_iterator::{testing, ToLendingCursors, VecCursors};
+//!
+//! fn lend(vec: &mut Vec<i32>) -> VecCursors<'_, i32> {
+//!     vec.lend_cursors()
+//! }
+//!
+//! let make = testing::Borrowing::new(|| vec![1, 2, 3], lend);
+//! testing::assert_size_hint(make);
+//! testing::assert_fused(make);
+//! testing::assert_exact_size(make);
+//! # }
+//! ```
+
+use core::{fmt::Debug, num::NonZeroUsize};
+
+use crate::{ExactSizeLendingIterator, LendingIterator, SingleArgFnMut, SingleArgFnOnce};
+
+/// Creates the fresh lending iterators that the checkers compare.
+///
+/// Implemented for closures returning a lending iterator, and by [`Borrowing`] for
+/// lending iterators that borrow their state.
+pub trait Make {
+    /// The state that a lending iterator is made from.
+    type State;
+
+    /// The lending iterator borrowing a state for `'s`.
+    type Iter<'s>: LendingIterator
+    where
+        Self::State: 's;
+
+    /// Creates a fresh state.
+    fn state(&mut self) -> Self::State;
+
+    /// Creates a lending iterator from `state`.
+    fn lend<'s>(&mut self, state: &'s mut Self::State) -> Self::Iter<'s>;
+}
+
+impl<F, I> Make for F
+where
+    F: FnMut() -> I,
+    I: LendingIterator,
+{
+    type State = ();
+    type Iter<'s> = I;
+
+    #[inline]
+    fn state(&mut self) {}
+
+    #[inline]
+    fn lend(&mut self, _state: &mut ()) -> I {
+        self()
+    }
+}
+
+/// A [`Make`] for lending iterators that borrow their state, like
+/// [`HashMapEntries`](crate::HashMapEntries), which can't be returned from a closure.
+///
+/// Each lending iterator is made by `lend` from a fresh state made by `state`.
+/// Closures can't return values that borrow their arguments, so `lend` is usually
+/// a `fn`. Since the lending iterators aren't `'static`, their items can't be
+/// projected for [`assert_nth`].
+#[derive(Clone, Copy, Debug)]
+pub struct Borrowing<S, L> {
+    state: S,
+    lend: L,
+}
+
+impl<S, L> Borrowing<S, L> {
+    /// Creates a `Borrowing` that makes lending iterators with `lend` from states
+    /// made by `state`.
+    pub fn new(state: S, lend: L) -> Self {
+        Borrowing { state, lend }
+    }
+}
+
+impl<S, L, T> Make for Borrowing<S, L>
+where
+    S: FnMut() -> T,
+    L: for<'s> SingleArgFnMut<&'s mut T>,
+    for<'s> <L as SingleArgFnOnce<&'s mut T>>::Output: LendingIterator,
+{
+    type State = T;
+    type Iter<'s> = <L as SingleArgFnOnce<&'s mut T>>::Output where T: 's;
+
+    #[inline]
+    fn state(&mut self) -> T {
+        (self.state)()
+    }
+
+    #[inline]
+    fn lend<'s>(&mut self, state: &'s mut T) -> Self::Iter<'s> {
+        (self.lend)(state)
+    }
+}
+
+/// Asserts that [`size_hint`](LendingIterator::size_hint) bounds the number of
+/// remaining items before every call to `next`.
+///
+/// # Panics
+///
+/// If a bound is wrong, or if `make` creates lending iterators of different lengths.
+#[track_caller]
+pub fn assert_size_hint<M: Make>(mut make: M) {
+    let len = count(&mut make);
+    let mut state = make.state();
+    let mut iter = make.lend(&mut state);
+    for consumed in 0..=len {
+        let remaining = len - consumed;
+        let (lower, upper) = iter.size_hint();
+        assert!(
+            lower <= remaining,
+            "size_hint lower bound {lower} is more than the {remaining} remaining items after {consumed} items"
+        );
+        if let Some(upper) = upper {
+            assert!(
+                remaining <= upper,
+                "size_hint upper bound {upper} is less than the {remaining} remaining items after {consumed} items"
+            );
+        }
+        assert_eq!(
+            iter.next().is_some(),
+            remaining > 0,
+            "`make` created lending iterators of different lengths"
+        );
+    }
+}
+
+/// Asserts that once the lending iterator returns `None`, it keeps doing so,
+/// and its [`size_hint`](LendingIterator::size_hint) has a lower bound of zero.
+///
+/// This is not required of lending iterators in general, but of any that claim
+/// to be fused, like [`Iterator`]s implementing [`FusedIterator`](core::iter::FusedIterator).
+///
+/// # Panics
+///
+/// If the lending iterator returns an item after `None`.
+#[track_caller]
+pub fn assert_fused<M: Make>(mut make: M) {
+    let mut state = make.state();
+    let mut iter = make.lend(&mut state);
+    let len = iter.by_ref().count();
+    for extra in 1..=3 {
+        assert_eq!(
+            iter.size_hint().0,
+            0,
+            "size_hint lower bound isn't zero after returning None"
+        );
+        assert!(
+            iter.next().is_none(),
+            "returned an item on call {extra} after the last of {len} items"
+        );
+    }
+    assert!(
+        iter.nth(0).is_none() && iter.advance_by(1).is_err(),
+        "advanced after the last of {len} items"
+    );
+}
+
+/// Asserts that [`nth`](LendingIterator::nth) and
+/// [`advance_by`](LendingIterator::advance_by) agree with calling `next`
+/// repeatedly, for every `n` up to past the end.
+///
+/// Items are compared after being passed to `project`.
+///
+/// # Panics
+///
+/// If `nth` returns a different item than `next` would, if `advance_by` returns
+/// the wrong result, or if either leaves the lending iterator at a different position.
+#[track_caller]
+pub fn assert_nth<M, P, T>(mut make: M, mut project: P)
+where
+    M: Make,
+    P: for<'s> FnMut(<M::Iter<'s> as LendingIterator>::Item<'_>) -> T,
+    T: PartialEq + Debug,
+{
+    let len = count(&mut make);
+    for n in 0..len + 3 {
+        let (mut state, mut expected_state) = (make.state(), make.state());
+        let mut iter = make.lend(&mut state);
+        let item = iter.nth(n).map(&mut project);
+        let mut expected = make.lend(&mut expected_state);
+        skip(&mut expected, n.min(len));
+        let expected_item = if n < len {
+            expected.next().map(&mut project)
+        } else {
+            None
+        };
+        assert_eq!(item, expected_item, "nth({n}) of {len} items");
+        if n < len {
+            assert_rest_eq(iter, expected, &mut project, "nth", n);
+        }
+
+        let (mut state, mut expected_state) = (make.state(), make.state());
+        let mut iter = make.lend(&mut state);
+        assert_eq!(
+            iter.advance_by(n),
+            NonZeroUsize::new(n.saturating_sub(len)).map_or(Ok(()), Err),
+            "advance_by({n}) of {len} items"
+        );
+        if n <= len {
+            let mut expected = make.lend(&mut expected_state);
+            skip(&mut expected, n);
+            assert_rest_eq(iter, expected, &mut project, "advance_by", n);
+        }
+    }
+}
+
+// Counts the items of a fresh lending iterator.
+fn count<M: Make>(make: &mut M) -> usize {
+    let mut state = make.state();
+    make.lend(&mut state).count()
+}
+
+// Advances `iter` with `next`.
+fn skip<I: LendingIterator>(iter: &mut I, n: usize) {
+    for _ in 0..n {
+        iter.next();
+    }
+}
+
+#[track_caller]
+fn assert_rest_eq<I, P, T>(mut iter: I, mut expected: I, project: &mut P, method: &str, n: usize)
+where
+    I: LendingIterator,
+    P: FnMut(I::Item<'_>) -> T,
+    T: PartialEq + Debug,
+{
+    for after in 0.. {
+        let item = iter.next().map(&mut *project);
+        let expected_item = expected.next().map(&mut *project);
+        assert_eq!(item, expected_item, "item {after} after {method}({n})");
+        if expected_item.is_none() {
+            break;
+        }
+    }
+}
+
+/// Asserts that [`len`](ExactSizeLendingIterator::len) is the number of remaining
+/// items before every call to `next`, and that
+/// [`is_empty`](ExactSizeLendingIterator::is_empty) agrees with it.
+///
+/// # Panics
+///
+/// If the length is wrong, or if `make` creates lending iterators of different lengths.
+#[track_caller]
+pub fn assert_exact_size<M>(mut make: M)
+where
+    M: Make,
+    for<'s> M::Iter<'s>: ExactSizeLendingIterator,
+{
+    let len = count(&mut make);
+    let mut state = make.state();
+    let mut iter = make.lend(&mut state);
+    for consumed in 0..=len {
+        let remaining = len - consumed;
+        assert_eq!(
+            iter.len(),
+            remaining,
+            "len after {consumed} of {len} items"
+        );
+        assert_eq!(
+            iter.is_empty(),
+            remaining == 0,
+            "is_empty after {consumed} of {len} items"
+        );
+        assert_eq!(
+            iter.next().is_some(),
+            remaining > 0,
+            "`make` created lending iterators of different lengths"
+        );
+    }
+}
+
+/// A non-fused wrapper that returns `None` `countdown` times before lending the
+/// items of `iter`.
+///
+/// Adapters should behave like their [`Iterator`] counterparts over it.
+///
+/// Implements both [`LendingIterator`] and [`Iterator`], depending on `I`, so
+/// the same adversarial input can be given to both.
+#[derive(Clone, Debug)]
+pub struct Delay<I> {
+    countdown: usize,
+    iter: I,
+}
+
+impl<I> Delay<I> {
+    /// Creates a `Delay` that returns `None` `countdown` times before lending
+    /// the items of `iter`.
+    pub fn new(countdown: usize, iter: I) -> Self {
+        Delay { countdown, iter }
+    }
+}
+
+// Generally we avoid implementing both Iterator and LendingIterator
+// for the same type. Here the bounds of the arguments are known not to collide.
+impl<I: LendingIterator> LendingIterator for Delay<I> {
+    type Item<'a> = I::Item<'a> where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        if self.countdown == 0 {
+            self.iter.next()
+        } else {
+            self.countdown -= 1;
+            None
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Delay<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.countdown == 0 {
+            self.iter.next()
+        } else {
+            self.countdown -= 1;
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{SliceWindowsMut, ToLendingIterator, ToLendingSliceWindows};
+
+    #[test]
+    fn adapters() {
+        let make = || (0..7).into_lending().step_by(2).skip(1).chain((0..3).into_lending());
+        assert_size_hint(make);
+        assert_fused(make);
+        assert_nth(make, |x| x);
+        assert_size_hint(|| (0..7).into_lending().filter(|x: &i32| x % 3 == 0));
+        assert_exact_size(|| (0..7).into_lending().enumerate().take(4));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn windows() {
+        for size in 1..4 {
+            let make = || (0..5).windows_mut(size);
+            assert_size_hint(make);
+            assert_fused(make);
+            assert_nth(make, |w: &mut [i32]| w.to_vec());
+            assert_exact_size(make);
+        }
+    }
+
+    #[test]
+    fn borrowing() {
+        fn lend(buffer: &mut [i32; 4]) -> SliceWindowsMut<&mut [i32]> {
+            buffer[..].lend_windows_mut(2)
+        }
+
+        let make = Borrowing::new(|| [0, 1, 2, 3], lend);
+        assert_size_hint(make);
+        assert_fused(make);
+        assert_exact_size(make);
+    }
+
+    #[test]
+    fn delay() {
+        let mut lending = Delay::new(2, (0..2).into_lending());
+        let mut iter = Delay::new(2, 0..2);
+        for expected in [None, None, Some(0), Some(1), None] {
+            assert_eq!(LendingIterator::next(&mut lending), expected);
+            assert_eq!(Iterator::next(&mut iter), expected);
+        }
+    }
+
+    // Claims one more item than it has.
+    struct Liar(usize);
+
+    impl LendingIterator for Liar {
+        type Item<'a> = usize;
+
+        fn next(&mut self) -> Option<Self::Item<'_>> {
+            self.0 = self.0.checked_sub(1)?;
+            Some(self.0)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.0 + 1, Some(self.0 + 1))
+        }
+    }
+
+    #[test]
+    #[should_panic = "size_hint lower bound 4 is more than the 3 remaining items"]
+    fn liar() {
+        assert_size_hint(|| Liar(3));
+    }
+
+    #[test]
+    #[should_panic = "returned an item on call 1 after the last of 0 items"]
+    fn not_fused() {
+        assert_fused(|| Delay::new(1, (0..1).into_lending()));
+    }
+}
@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+use core::num::NonZeroUsize;
+
+use crate::{windows_advance_by, windows_push, ExactSizeLendingIterator, LendingIterator};
+
+/// A lending iterator over windows of a size known at compile time.
+///
+/// This `struct` is created by the [`array_windows`] method on [`ToLendingIterator`]. See
+/// its documentation for more.
+///
+/// [`ToLendingIterator`]: crate::ToLendingIterator
+/// [`array_windows`]: crate::ToLendingIterator::array_windows
+pub struct ArrayWindows<I: Iterator, const N: usize> {
+    iter: I,
+    buf: Vec<I::Item>,
+}
+
+impl<I: Iterator + Clone, const N: usize> Clone for ArrayWindows<I, N>
+where
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            buf: self.buf.clone(),
+        }
+    }
+
+    // Reuses the buffer, which matters when cycling.
+    fn clone_from(&mut self, source: &Self) {
+        self.iter.clone_from(&source.iter);
+        self.buf.clone_from(&source.buf);
+    }
+}
+
+impl<I: Iterator, const N: usize> ArrayWindows<I, N> {
+    const NON_ZERO: () = assert!(N != 0, "window size must be non-zero");
+
+    pub(crate) fn new(mut iter: I) -> Self {
+        let () = Self::NON_ZERO;
+        let buf = iter.by_ref().take(N - 1).collect();
+        Self { iter, buf }
+    }
+}
+
+impl<I: Iterator, const N: usize> LendingIterator for ArrayWindows<I, N> {
+    type Item<'a> = &'a [I::Item; N]
+        where
+            Self: 'a
+    ;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        let next = self.iter.next()?;
+        let window: &[_] = windows_push(&mut self.buf, N, next);
+        Some(window.try_into().expect("window has N items"))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The underlying iterator ran out before the first window was filled.
+        if self.buf.len() + 1 < N {
+            return (0, Some(0));
+        }
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        windows_advance_by(&mut self.iter, &mut self.buf, N, n)
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &[I::Item; N]) -> B,
+    {
+        let Self { iter, mut buf } = self;
+        iter.fold(init, move |acc, next| {
+            let window: &[_] = windows_push(&mut buf, N, next);
+            f(acc, window.try_into().expect("window has N items"))
+        })
+    }
+}
+
+impl<I: ExactSizeIterator, const N: usize> ExactSizeLendingIterator for ArrayWindows<I, N> {}
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn test() {
+        let sums = (0..6)
+            .array_windows()
+            .map(|&[a, b, c]: &[i32; 3]| a + b + c)
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(sums, vec![3, 6, 9, 12]);
+        assert_eq!((0..2).array_windows::<3>().next(), None);
+    }
+}
@@ -0,0 +1,105 @@
+use alloc::vec::Vec;
+use core::num::NonZeroUsize;
+
+use crate::{windows_advance_by, windows_push, ExactSizeLendingIterator, LendingIterator};
+
+/// A lending iterator over mutable windows of a size known at compile time.
+///
+/// This `struct` is created by the [`array_windows_mut`] method on [`ToLendingIterator`]. See
+/// its documentation for more.
+///
+/// [`ToLendingIterator`]: crate::ToLendingIterator
+/// [`array_windows_mut`]: crate::ToLendingIterator::array_windows_mut
+pub struct ArrayWindowsMut<I: Iterator, const N: usize> {
+    iter: I,
+    buf: Vec<I::Item>,
+}
+
+impl<I: Iterator + Clone, const N: usize> Clone for ArrayWindowsMut<I, N>
+where
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            buf: self.buf.clone(),
+        }
+    }
+
+    // Reuses the buffer, which matters when cycling.
+    fn clone_from(&mut self, source: &Self) {
+        self.iter.clone_from(&source.iter);
+        self.buf.clone_from(&source.buf);
+    }
+}
+
+impl<I: Iterator, const N: usize> ArrayWindowsMut<I, N> {
+    const NON_ZERO: () = assert!(N != 0, "window size must be non-zero");
+
+    pub(crate) fn new(mut iter: I) -> Self {
+        let () = Self::NON_ZERO;
+        let buf = iter.by_ref().take(N - 1).collect();
+        Self { iter, buf }
+    }
+}
+
+impl<I: Iterator, const N: usize> LendingIterator for ArrayWindowsMut<I, N> {
+    type Item<'a> = &'a mut [I::Item; N]
+        where
+            Self: 'a
+    ;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        let next = self.iter.next()?;
+        let window = windows_push(&mut self.buf, N, next);
+        Some(window.try_into().expect("window has N items"))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The underlying iterator ran out before the first window was filled.
+        if self.buf.len() + 1 < N {
+            return (0, Some(0));
+        }
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        windows_advance_by(&mut self.iter, &mut self.buf, N, n)
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &mut [I::Item; N]) -> B,
+    {
+        let Self { iter, mut buf } = self;
+        iter.fold(init, move |acc, next| {
+            let window = windows_push(&mut buf, N, next);
+            f(acc, window.try_into().expect("window has N items"))
+        })
+    }
+}
+
+impl<I: ExactSizeIterator, const N: usize> ExactSizeLendingIterator for ArrayWindowsMut<I, N> {}
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn test() {
+        let mut windows = (0..5).array_windows_mut();
+        let mut sums = Vec::new();
+        while let Some([a, b]) = windows.next() {
+            *b += *a;
+            sums.push(*b);
+        }
+        assert_eq!(sums, [1, 3, 6, 10]);
+        let mut windows = [1, 2, 3].into_iter().array_windows_mut::<2>();
+        let [a, b] = windows.next().unwrap();
+        std::mem::swap(a, b);
+        assert_eq!(windows.next(), Some(&mut [1, 3]));
+    }
+}
@@ -0,0 +1,118 @@
+use alloc::collections::btree_map::{self, BTreeMap};
+use core::mem;
+
+use crate::{ExactSizeLendingIterator, LendingIterator, MapEntry};
+
+/// A lending iterator over the entries of a [`BTreeMap`] in key order, which can be
+/// modified or removed while iterating.
+///
+/// The entries are moved out of the map up front, and put back as the lending
+/// iterator moves past them. Entries that haven't been visited are put back when
+/// the lending iterator is dropped.
+///
+/// This `struct` is created by the [`lend_entries`] method on [`ToLendingEntries`]. See
+/// its documentation for more.
+///
+/// [`ToLendingEntries`]: crate::ToLendingEntries
+/// [`lend_entries`]: crate::ToLendingEntries::lend_entries
+pub struct BTreeMapEntries<'a, K: Ord, V> {
+    // The entries that were kept so far.
+    map: &'a mut BTreeMap<K, V>,
+    current: Option<(K, V)>,
+    rest: btree_map::IntoIter<K, V>,
+}
+
+impl<'a, K: Ord, V> BTreeMapEntries<'a, K, V> {
+    pub(crate) fn new(map: &'a mut BTreeMap<K, V>) -> Self {
+        let rest = mem::take(map).into_iter();
+        BTreeMapEntries {
+            map,
+            current: None,
+            rest,
+        }
+    }
+
+    // Puts the last lent entry back, unless it was removed.
+    fn finish_current(&mut self) {
+        self.map.extend(self.current.take());
+    }
+}
+
+impl<K: Ord, V> LendingIterator for BTreeMapEntries<'_, K, V> {
+    type Item<'a> = MapEntry<'a, K, V>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        self.finish_current();
+        self.current = Some(self.rest.next()?);
+        Some(MapEntry::new(&mut self.current))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rest.size_hint()
+    }
+}
+
+impl<K: Ord, V> ExactSizeLendingIterator for BTreeMapEntries<'_, K, V> {}
+
+impl<K: Ord, V> Drop for BTreeMapEntries<'_, K, V> {
+    fn drop(&mut self) {
+        self.finish_current();
+        self.map.extend(&mut self.rest);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::collections::BTreeMap;
+
+    use crate::{testing, BTreeMapEntries, LendingIterator, ToLendingEntries};
+
+    #[test]
+    fn modify_and_remove() {
+        let mut map: BTreeMap<i32, i32> = (0..10).map(|i| (i, i)).collect();
+        let mut seen = Vec::new();
+        let mut removed = Vec::new();
+        let mut entries = map.lend_entries();
+        while let Some(mut entry) = entries.next() {
+            seen.push(*entry.key());
+            if entry.key() % 2 == 0 {
+                removed.push(entry.remove());
+            } else {
+                *entry.get_mut() *= 10;
+            }
+        }
+        drop(entries);
+        assert_eq!(seen, (0..10).collect::<Vec<_>>());
+        assert_eq!(removed, [0, 2, 4, 6, 8]);
+        assert_eq!(
+            map.into_iter().collect::<Vec<_>>(),
+            [(1, 10), (3, 30), (5, 50), (7, 70), (9, 90)]
+        );
+    }
+
+    #[test]
+    fn stop_early() {
+        let mut map: BTreeMap<i32, i32> = (0..5).map(|i| (i, i)).collect();
+        let mut entries = map.lend_entries();
+        assert_eq!(entries.next().unwrap().remove_entry(), (0, 0));
+        *entries.next().unwrap().into_mut() = 10;
+        drop(entries);
+        assert_eq!(map.into_iter().collect::<Vec<_>>(), [(1, 10), (2, 2), (3, 3), (4, 4)]);
+    }
+
+    #[test]
+    fn contracts() {
+        fn lend(map: &mut BTreeMap<i32, i32>) -> BTreeMapEntries<'_, i32, i32> {
+            map.lend_entries()
+        }
+
+        let make = testing::Borrowing::new(|| (0..5).map(|i| (i, i * i)).collect(), lend);
+        testing::assert_size_hint(make);
+        testing::assert_fused(make);
+        testing::assert_exact_size(make);
+    }
+}
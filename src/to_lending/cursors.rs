@@ -0,0 +1,509 @@
+// Lending cursors into a `LinkedList` is one of the things this module is for.
+#![allow(clippy::linkedlist)]
+
+use alloc::{
+    collections::{LinkedList, VecDeque},
+    vec::{self, Vec},
+};
+use core::{borrow::BorrowMut, mem};
+
+use crate::{ExactSizeLendingIterator, LendingIterator};
+
+mod private {
+    // The operations `Cursors` needs, kept out of the public API.
+    pub trait Sealed: Default {
+        type Item;
+        // The elements that haven't been visited yet.
+        type Rest;
+
+        // Takes the elements out, leaving an empty collection with room for as many.
+        fn take_rest(&mut self) -> Self::Rest;
+
+        fn pop_front(rest: &mut Self::Rest) -> Option<Self::Item>;
+
+        fn rest_len(rest: &Self::Rest) -> usize;
+
+        fn append_rest(&mut self, rest: &mut Self::Rest);
+
+        fn push_back(&mut self, value: Self::Item);
+
+        fn append(&mut self, other: &mut Self);
+    }
+}
+
+use private::Sealed;
+
+/// A collection that [`Cursors`] can edit while iterating: a [`Vec`], [`VecDeque`]
+/// or [`LinkedList`].
+///
+/// This trait is sealed, and can't be implemented outside of this crate.
+pub trait CursorCollection: Sealed {}
+
+impl<T> Sealed for Vec<T> {
+    type Item = T;
+    type Rest = vec::IntoIter<T>;
+
+    fn take_rest(&mut self) -> Self::Rest {
+        let capacity = self.len();
+        mem::replace(self, Vec::with_capacity(capacity)).into_iter()
+    }
+
+    fn pop_front(rest: &mut Self::Rest) -> Option<T> {
+        rest.next()
+    }
+
+    fn rest_len(rest: &Self::Rest) -> usize {
+        rest.len()
+    }
+
+    fn append_rest(&mut self, rest: &mut Self::Rest) {
+        self.extend(rest);
+    }
+
+    fn push_back(&mut self, value: T) {
+        self.push(value);
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        self.append(other);
+    }
+}
+
+impl<T> CursorCollection for Vec<T> {}
+
+impl<T> Sealed for VecDeque<T> {
+    type Item = T;
+    type Rest = VecDeque<T>;
+
+    fn take_rest(&mut self) -> Self::Rest {
+        let capacity = self.len();
+        mem::replace(self, VecDeque::with_capacity(capacity))
+    }
+
+    fn pop_front(rest: &mut Self::Rest) -> Option<T> {
+        rest.pop_front()
+    }
+
+    fn rest_len(rest: &Self::Rest) -> usize {
+        rest.len()
+    }
+
+    fn append_rest(&mut self, rest: &mut Self::Rest) {
+        self.append(rest);
+    }
+
+    fn push_back(&mut self, value: T) {
+        self.push_back(value);
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        self.append(other);
+    }
+}
+
+impl<T> CursorCollection for VecDeque<T> {}
+
+// The unvisited elements are appended back with their nodes.
+impl<T> Sealed for LinkedList<T> {
+    type Item = T;
+    type Rest = LinkedList<T>;
+
+    fn take_rest(&mut self) -> Self::Rest {
+        mem::take(self)
+    }
+
+    fn pop_front(rest: &mut Self::Rest) -> Option<T> {
+        rest.pop_front()
+    }
+
+    fn rest_len(rest: &Self::Rest) -> usize {
+        rest.len()
+    }
+
+    fn append_rest(&mut self, rest: &mut Self::Rest) {
+        self.append(rest);
+    }
+
+    fn push_back(&mut self, value: T) {
+        self.push_back(value);
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        self.append(other);
+    }
+}
+
+impl<T> CursorCollection for LinkedList<T> {}
+
+// The state of `Cursors` and `IntoCursors`, which hold the collection as `B`.
+struct Editor<B, C: CursorCollection> {
+    // The elements that were kept or inserted so far.
+    collection: B,
+    current: Option<C::Item>,
+    inserted: C,
+    rest: C::Rest,
+}
+
+impl<B: BorrowMut<C>, C: CursorCollection> Editor<B, C> {
+    fn new(mut collection: B) -> Self {
+        let rest = collection.borrow_mut().take_rest();
+        Editor {
+            collection,
+            current: None,
+            inserted: C::default(),
+            rest,
+        }
+    }
+
+    // Puts the last lent element back, unless it was removed, followed by the
+    // elements inserted after it.
+    fn finish_current(&mut self) {
+        let collection = self.collection.borrow_mut();
+        if let Some(current) = self.current.take() {
+            collection.push_back(current);
+        }
+        collection.append(&mut self.inserted);
+    }
+
+    fn next(&mut self) -> Option<Cursor<'_, C>> {
+        self.finish_current();
+        self.current = Some(C::pop_front(&mut self.rest)?);
+        Some(Cursor {
+            current: &mut self.current,
+            before: self.collection.borrow_mut(),
+            after: &mut self.inserted,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = C::rest_len(&self.rest);
+        (len, Some(len))
+    }
+
+    // Puts back the last lent element and the elements that haven't been visited.
+    fn finish(&mut self) {
+        self.finish_current();
+        self.collection.borrow_mut().append_rest(&mut self.rest);
+    }
+}
+
+/// A lending iterator over cursors to the elements of a [`CursorCollection`], which
+/// can keep, modify or remove each element and insert new ones around it.
+///
+/// The elements are moved out of the collection up front, and moved back one at a
+/// time as iteration proceeds. Elements that haven't been visited are put back
+/// when the lending iterator is dropped.
+///
+/// This isn't done in place. For a [`Vec`] or [`VecDeque`], the collection gets a
+/// new buffer with room for all the elements, so both buffers are allocated until
+/// the lending iterator is dropped. For a [`LinkedList`], each visited element is
+/// moved into a new node, since there is no stable way to lend an element while
+/// keeping its node linked.
+///
+/// This `struct` is created by the [`lend_cursors`] method on [`ToLendingCursors`]. See
+/// its documentation for more.
+///
+/// [`ToLendingCursors`]: crate::ToLendingCursors
+/// [`lend_cursors`]: crate::ToLendingCursors::lend_cursors
+pub struct Cursors<'a, C: CursorCollection> {
+    editor: Editor<&'a mut C, C>,
+}
+
+/// The [`Cursors`] of a [`Vec`].
+pub type VecCursors<'a, T> = Cursors<'a, Vec<T>>;
+
+/// The [`Cursors`] of a [`VecDeque`].
+pub type VecDequeCursors<'a, T> = Cursors<'a, VecDeque<T>>;
+
+/// The [`Cursors`] of a [`LinkedList`].
+pub type LinkedListCursors<'a, T> = Cursors<'a, LinkedList<T>>;
+
+impl<'a, C: CursorCollection> Cursors<'a, C> {
+    pub(crate) fn new(collection: &'a mut C) -> Self {
+        Cursors {
+            editor: Editor::new(collection),
+        }
+    }
+}
+
+impl<C: CursorCollection> LendingIterator for Cursors<'_, C> {
+    type Item<'a> = Cursor<'a, C>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        self.editor.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.editor.size_hint()
+    }
+}
+
+impl<C: CursorCollection> ExactSizeLendingIterator for Cursors<'_, C> {}
+
+impl<C: CursorCollection> Drop for Cursors<'_, C> {
+    fn drop(&mut self) {
+        self.editor.finish();
+    }
+}
+
+/// A lending iterator over cursors to the elements of a [`CursorCollection`] that
+/// it owns, like [`Cursors`].
+///
+/// The collection is taken back with [`into_inner`](Self::into_inner). Owning it
+/// makes the lending iterator `'static` when the elements are, so it can be used with
+/// adapters taking closures, like [`filter`](LendingIterator::filter).
+///
+/// This `struct` is created by the [`into_lending_cursors`] method on
+/// [`ToLendingCursors`]. See its documentation for more.
+///
+/// [`ToLendingCursors`]: crate::ToLendingCursors
+/// [`into_lending_cursors`]: crate::ToLendingCursors::into_lending_cursors
+pub struct IntoCursors<C: CursorCollection> {
+    editor: Editor<C, C>,
+}
+
+impl<C: CursorCollection> IntoCursors<C> {
+    pub(crate) fn new(collection: C) -> Self {
+        IntoCursors {
+            editor: Editor::new(collection),
+        }
+    }
+
+    /// Returns the collection, with the changes made through the cursors. Elements
+    /// that haven't been visited are put back.
+    pub fn into_inner(mut self) -> C {
+        self.editor.finish();
+        self.editor.collection
+    }
+}
+
+impl<C: CursorCollection> LendingIterator for IntoCursors<C> {
+    type Item<'a> = Cursor<'a, C>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        self.editor.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.editor.size_hint()
+    }
+}
+
+impl<C: CursorCollection> ExactSizeLendingIterator for IntoCursors<C> {}
+
+/// A cursor to an element of a [`CursorCollection`], lent by [`Cursors`] and
+/// [`IntoCursors`].
+///
+/// Dropping the cursor keeps the element, like [`keep`](Self::keep).
+pub struct Cursor<'a, C: CursorCollection> {
+    // Always `Some` while the cursor exists, since only `remove` takes it.
+    current: &'a mut Option<C::Item>,
+    before: &'a mut C,
+    after: &'a mut C,
+}
+
+/// A [`Cursor`] to an element of a [`Vec`].
+pub type VecCursor<'a, T> = Cursor<'a, Vec<T>>;
+
+/// A [`Cursor`] to an element of a [`VecDeque`].
+pub type VecDequeCursor<'a, T> = Cursor<'a, VecDeque<T>>;
+
+/// A [`Cursor`] to an element of a [`LinkedList`].
+pub type LinkedListCursor<'a, T> = Cursor<'a, LinkedList<T>>;
+
+impl<C: CursorCollection> Cursor<'_, C> {
+    /// Returns a reference to the element.
+    #[must_use]
+    pub fn get(&self) -> &C::Item {
+        match &*self.current {
+            Some(current) => current,
+            None => unreachable!("cursor's element is present"),
+        }
+    }
+
+    /// Returns a mutable reference to the element.
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut C::Item {
+        match &mut *self.current {
+            Some(current) => current,
+            None => unreachable!("cursor's element is present"),
+        }
+    }
+
+    /// Removes the element from the collection and returns it.
+    ///
+    /// Values inserted by this cursor stay in the collection.
+    // Like with `Vec::remove`, discarding the element is fine.
+    #[allow(clippy::must_use_candidate)]
+    pub fn remove(self) -> C::Item {
+        match self.current.take() {
+            Some(current) => current,
+            None => unreachable!("cursor's element is present"),
+        }
+    }
+
+    /// Inserts `value` before the element, and after any values inserted before it
+    /// by this cursor. Inserted values aren't lent by [`Cursors`].
+    pub fn insert_before(&mut self, value: C::Item) {
+        self.before.push_back(value);
+    }
+
+    /// Inserts `value` after the element, and after any values inserted after it
+    /// by this cursor. Inserted values aren't lent by [`Cursors`].
+    pub fn insert_after(&mut self, value: C::Item) {
+        self.after.push_back(value);
+    }
+
+    /// Keeps the element in the collection. This is the same as dropping the cursor.
+    pub fn keep(self) {}
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::collections::{LinkedList, VecDeque};
+
+    use crate::{testing, CursorCollection, Cursors, LendingIterator, ToLendingCursors, VecCursor};
+
+    #[test]
+    fn edit_vec() {
+        let mut vec: Vec<i32> = (0..8).collect();
+        let mut removed = Vec::new();
+        let mut cursors = vec.lend_cursors();
+        while let Some(mut cursor) = cursors.next() {
+            match cursor.get() % 4 {
+                0 => removed.push(cursor.remove()),
+                1 => *cursor.get_mut() *= 10,
+                2 => {
+                    cursor.insert_after(-1);
+                    cursor.insert_after(-2);
+                }
+                _ => {
+                    cursor.insert_before(-3);
+                    cursor.insert_after(-4);
+                    removed.push(cursor.remove());
+                }
+            }
+        }
+        drop(cursors);
+        assert_eq!(vec, [10, 2, -1, -2, -3, -4, 50, 6, -1, -2, -3, -4]);
+        assert_eq!(removed, [0, 3, 4, 7]);
+    }
+
+    #[test]
+    fn edit_deque_and_list() {
+        let mut deque: VecDeque<i32> = (0..6).collect();
+        deque.rotate_left(2);
+        let mut list: LinkedList<i32> = deque.iter().copied().collect();
+        negate_odd_positions(&mut deque);
+        negate_odd_positions(&mut list);
+        assert_eq!(deque, [-3, 3, -5, 5, -1, 1]);
+        assert_eq!(list, LinkedList::from([-3, 3, -5, 5, -1, 1]));
+    }
+
+    // Removes the elements at even positions, and inserts the negation of the
+    // others before them.
+    fn negate_odd_positions<C: CursorCollection<Item = i32>>(collection: &mut C) {
+        let mut cursors = Cursors::new(collection).enumerate();
+        while let Some((i, mut cursor)) = cursors.next() {
+            if i % 2 == 0 {
+                let removed = cursor.remove();
+                assert_eq!(removed % 2, 0);
+            } else {
+                let value = -*cursor.get();
+                cursor.insert_before(value);
+            }
+        }
+    }
+
+    #[test]
+    fn stop_early() {
+        let mut vec: Vec<i32> = (0..6).collect();
+        let mut cursors = vec.lend_cursors();
+        assert_eq!(cursors.next().unwrap().remove(), 0);
+        cursors.next().unwrap().insert_after(10);
+        let mut third = cursors.next().unwrap();
+        *third.get_mut() = 20;
+        drop(cursors);
+        assert_eq!(vec, [1, 10, 20, 3, 4, 5]);
+
+        let mut list: LinkedList<i32> = (0..4).collect();
+        let mut cursors = list.lend_cursors();
+        let mut first = cursors.next().unwrap();
+        first.insert_before(-1);
+        first.insert_after(10);
+        drop(cursors);
+        assert_eq!(list, LinkedList::from([-1, 0, 10, 1, 2, 3]));
+    }
+
+    #[test]
+    fn filter_enumerate() {
+        // Removes the first, third and fifth element not divisible by 3, and
+        // scales the others.
+        fn edit((i, mut cursor): (usize, VecCursor<'_, i32>), removed: &mut Vec<i32>) {
+            if i % 2 == 0 {
+                removed.push(cursor.remove());
+            } else {
+                *cursor.get_mut() *= 10;
+                cursor.insert_after(-1);
+            }
+        }
+
+        let not_divisible = |cursor: &VecCursor<'_, i32>| cursor.get() % 3 != 0;
+        let mut removed = Vec::new();
+        let vec: Vec<i32> = (0..10).collect();
+        let mut cursors = vec.into_lending_cursors().filter(not_divisible).enumerate();
+        while let Some(item) = cursors.next() {
+            edit(item, &mut removed);
+        }
+        assert_eq!(removed, [1, 4, 7]);
+
+        removed.clear();
+        let vec: Vec<i32> = (0..10).collect();
+        let mut cursors = vec.into_lending_cursors();
+        let mut filtered = cursors.filter_by_ref(not_divisible).enumerate();
+        while let Some(item) = filtered.next() {
+            edit(item, &mut removed);
+        }
+        drop(filtered);
+        assert_eq!(removed, [1, 4, 7]);
+        assert_eq!(cursors.into_inner(), [0, 20, -1, 3, 50, -1, 6, 80, -1, 9]);
+    }
+
+    #[test]
+    fn into_inner() {
+        let mut cursors = LinkedList::from([1, 2, 3, 4]).into_lending_cursors();
+        cursors.next().unwrap().insert_before(0);
+        *cursors.next().unwrap().get_mut() = 20;
+        assert_eq!(cursors.into_inner(), LinkedList::from([0, 1, 20, 3, 4]));
+    }
+
+    #[test]
+    fn contracts() {
+        fn lend<C: CursorCollection>(collection: &mut C) -> Cursors<'_, C> {
+            collection.lend_cursors()
+        }
+
+        fn check<C: CursorCollection + 'static>(state: fn() -> C) {
+            let make = testing::Borrowing::new(state, lend);
+            testing::assert_size_hint(make);
+            testing::assert_fused(make);
+            testing::assert_exact_size(make);
+            let make = || state().into_lending_cursors();
+            testing::assert_size_hint(make);
+            testing::assert_fused(make);
+            testing::assert_exact_size(make);
+        }
+
+        check(|| vec![1, 2, 3, 4]);
+        check(|| VecDeque::from([1, 2, 3, 4]));
+        check(|| LinkedList::from([1, 2, 3, 4]));
+    }
+}
@@ -0,0 +1,152 @@
+use alloc::vec::{self, Vec};
+use core::hash::{BuildHasher, Hash};
+use std::collections::HashMap;
+
+use crate::{ExactSizeLendingIterator, LendingIterator, MapEntry};
+
+/// A lending iterator over the entries of a [`HashMap`], which can be modified or
+/// removed while iterating.
+///
+/// The entries are moved out of the map up front, and put back as the lending
+/// iterator moves past them. Entries that haven't been visited are put back when
+/// the lending iterator is dropped.
+///
+/// This `struct` is created by the [`lend_entries`] method on [`ToLendingEntries`]. See
+/// its documentation for more.
+///
+/// [`ToLendingEntries`]: crate::ToLendingEntries
+/// [`lend_entries`]: crate::ToLendingEntries::lend_entries
+pub struct HashMapEntries<'a, K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    // The entries that were kept so far.
+    map: &'a mut HashMap<K, V, S>,
+    current: Option<(K, V)>,
+    rest: vec::IntoIter<(K, V)>,
+}
+
+impl<'a, K, V, S> HashMapEntries<'a, K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    pub(crate) fn new(map: &'a mut HashMap<K, V, S>) -> Self {
+        // Draining keeps the map's capacity, so putting the entries back doesn't
+        // reallocate.
+        let rest = map.drain().collect::<Vec<_>>().into_iter();
+        HashMapEntries {
+            map,
+            current: None,
+            rest,
+        }
+    }
+
+    // Puts the last lent entry back, unless it was removed.
+    fn finish_current(&mut self) {
+        self.map.extend(self.current.take());
+    }
+}
+
+impl<K, V, S> LendingIterator for HashMapEntries<'_, K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Item<'a> = MapEntry<'a, K, V>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        self.finish_current();
+        self.current = Some(self.rest.next()?);
+        Some(MapEntry::new(&mut self.current))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rest.size_hint()
+    }
+}
+
+impl<K, V, S> ExactSizeLendingIterator for HashMapEntries<'_, K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+}
+
+impl<K, V, S> Drop for HashMapEntries<'_, K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn drop(&mut self) {
+        self.finish_current();
+        self.map.extend(&mut self.rest);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{hash_map::RandomState, HashMap};
+
+    use crate::{testing, ExactSizeLendingIterator, HashMapEntries, LendingIterator, ToLendingEntries};
+
+    #[test]
+    fn modify_and_remove() {
+        let mut map: HashMap<i32, String> = (0..10).map(|i| (i, i.to_string())).collect();
+        let mut entries = map.lend_entries();
+        assert_eq!(entries.len(), 10);
+        let mut removed = Vec::new();
+        while let Some(mut entry) = entries.next() {
+            if entry.key() % 3 == 0 {
+                removed.push(entry.remove());
+            } else {
+                entry.get_mut().push('!');
+            }
+        }
+        drop(entries);
+        let mut remaining: Vec<_> = map.into_iter().collect();
+        remaining.sort();
+        let expected: Vec<_> = [1, 2, 4, 5, 7, 8]
+            .into_iter()
+            .map(|i| (i, format!("{i}!")))
+            .collect();
+        assert_eq!(remaining, expected);
+        removed.sort();
+        assert_eq!(removed, ["0", "3", "6", "9"]);
+    }
+
+    #[test]
+    fn stop_early() {
+        let mut map: HashMap<i32, i32> = (0..6).map(|i| (i, i)).collect();
+        let mut entries = map.lend_entries();
+        let mut removed = Vec::new();
+        for _ in 0..2 {
+            let (key, value) = entries.next().unwrap().remove_entry();
+            assert_eq!(key, value);
+            removed.push(key);
+        }
+        *entries.next().unwrap().into_mut() = 10;
+        drop(entries);
+        assert_eq!(map.len(), 4);
+        assert!(removed.iter().all(|key| !map.contains_key(key)));
+        assert_eq!(map.values().filter(|value| **value == 10).count(), 1);
+    }
+
+    #[test]
+    fn contracts() {
+        fn lend(map: &mut HashMap<i32, i32>) -> HashMapEntries<'_, i32, i32, RandomState> {
+            map.lend_entries()
+        }
+
+        let map: HashMap<i32, i32> = (0..5).map(|i| (i, i * i)).collect();
+        let make = testing::Borrowing::new(|| map.clone(), lend);
+        testing::assert_size_hint(make);
+        testing::assert_fused(make);
+        testing::assert_exact_size(make);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::LendingIterator;
+use core::num::NonZeroUsize;
+
+use crate::{ExactSizeLendingIterator, LendingIterator};
 
 /// A lending iterator that iterates over an iterator.
 #[derive(Clone)]
@@ -22,4 +24,43 @@ impl<I: Iterator> LendingIterator for IntoLending<I> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.iter.size_hint()
     }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        iter_advance_by(&mut self.iter, n)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        self.iter.nth(n)
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, I::Item) -> B,
+    {
+        self.iter.fold(init, f)
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for IntoLending<I> {}
+
+/// Advances `iter` by `n` items, like the unstable [`Iterator::advance_by`].
+///
+/// The items that `size_hint` guarantees are skipped with a single call to
+/// [`Iterator::nth`], which many iterators implement in constant time.
+pub(crate) fn iter_advance_by<I: Iterator>(iter: &mut I, n: usize) -> Result<(), NonZeroUsize> {
+    let skip = iter.size_hint().0.min(n);
+    if skip > 0 && iter.nth(skip - 1).is_none() {
+        // The lower bound was wrong, so how many items were skipped is unknown.
+        return NonZeroUsize::new(n).map_or(Ok(()), Err);
+    }
+    for i in skip..n {
+        if iter.next().is_none() {
+            // `i` is always less than `n`, so this is always `Err`.
+            return NonZeroUsize::new(n - i).map_or(Ok(()), Err);
+        }
+    }
+    Ok(())
 }
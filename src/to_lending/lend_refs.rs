@@ -1,4 +1,6 @@
-use crate::LendingIterator;
+use core::num::NonZeroUsize;
+
+use crate::{iter_advance_by, ExactSizeLendingIterator, LendingIterator};
 
 /// A lending iterator that given an iterator, lends
 /// references to the given iterator's items.
@@ -24,10 +26,31 @@ where
         self.item = self.iter.next();
         self.item.as_ref()
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        iter_advance_by(&mut self.iter, n)
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &I::Item) -> B,
+    {
+        self.iter.fold(init, |acc, item| f(acc, &item))
+    }
 }
+
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for LendRefs<I> {}
+
 #[cfg(test)]
 mod test {
-    use crate::{LendingIterator, ToLendingIterator};
+    use crate::{testing, LendingIterator, ToLendingIterator};
     #[derive(Clone, Eq, PartialEq, Debug)]
     struct Foo(usize);
     struct W {
@@ -40,6 +63,7 @@ mod test {
             Some(&self.x)
         }
     }
+
     #[test]
     fn test() {
         let mut xs = Vec::new();
@@ -49,6 +73,14 @@ mod test {
         assert_eq!(xs, vec![Foo(0), Foo(1), Foo(2)]);
     }
 
+    #[test]
+    fn contracts() {
+        let make = || test_helper().take(4);
+        testing::assert_size_hint(make);
+        testing::assert_fused(make);
+        testing::assert_nth(make, |x: &Foo| x.clone());
+    }
+
     fn test_helper() -> impl for<'a> LendingIterator<Item<'a> = &'a Foo> {
         let w = W { x: Foo(0) };
         std::iter::once(Foo(0)).lend_refs().chain(w)
@@ -1,4 +1,6 @@
-use crate::LendingIterator;
+use core::num::NonZeroUsize;
+
+use crate::{iter_advance_by, ExactSizeLendingIterator, LendingIterator};
 
 /// A lending iterator that given an iterator, lends
 /// mutable references to the given iterator's items.
@@ -21,8 +23,28 @@ impl<I: Iterator> LendingIterator for LendRefsMut<I> {
         self.item = self.iter.next();
         self.item.as_mut()
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        iter_advance_by(&mut self.iter, n)
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &mut I::Item) -> B,
+    {
+        self.iter.fold(init, |acc, mut item| f(acc, &mut item))
+    }
 }
 
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for LendRefsMut<I> {}
+
 #[cfg(test)]
 mod test {
     use crate::{LendingIterator, ToLendingIterator};
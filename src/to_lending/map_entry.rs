@@ -0,0 +1,67 @@
+/// An entry of a map, lent by [`HashMapEntries`](crate::HashMapEntries) and
+/// [`BTreeMapEntries`](crate::BTreeMapEntries).
+///
+/// Dropping the entry keeps it in the map.
+pub struct MapEntry<'a, K, V> {
+    // Always `Some` while the entry is lent, since only `remove_entry` takes it.
+    entry: &'a mut Option<(K, V)>,
+}
+
+impl<'a, K, V> MapEntry<'a, K, V> {
+    pub(crate) fn new(entry: &'a mut Option<(K, V)>) -> Self {
+        MapEntry { entry }
+    }
+
+    /// Returns a reference to the key of the entry.
+    #[must_use]
+    pub fn key(&self) -> &K {
+        match &*self.entry {
+            Some((key, _)) => key,
+            None => unreachable!("lent entry is present"),
+        }
+    }
+
+    /// Returns a reference to the value of the entry.
+    #[must_use]
+    pub fn get(&self) -> &V {
+        match &*self.entry {
+            Some((_, value)) => value,
+            None => unreachable!("lent entry is present"),
+        }
+    }
+
+    /// Returns a mutable reference to the value of the entry.
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut V {
+        match &mut *self.entry {
+            Some((_, value)) => value,
+            None => unreachable!("lent entry is present"),
+        }
+    }
+
+    /// Converts the entry into a mutable reference to its value, which lives as
+    /// long as the entry was lent for.
+    #[must_use]
+    pub fn into_mut(self) -> &'a mut V {
+        match self.entry {
+            Some((_, value)) => value,
+            None => unreachable!("lent entry is present"),
+        }
+    }
+
+    /// Removes the entry from the map and returns its value.
+    // Like with `HashMap::remove`, discarding the value is fine.
+    #[allow(clippy::must_use_candidate)]
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    /// Removes the entry from the map and returns its key and value.
+    #[allow(clippy::must_use_candidate)]
+    pub fn remove_entry(self) -> (K, V) {
+        match self.entry.take() {
+            Some(entry) => entry,
+            None => unreachable!("lent entry is present"),
+        }
+    }
+}
@@ -1,10 +1,52 @@
+#[cfg(feature = "alloc")]
+mod array_windows;
+#[cfg(feature = "alloc")]
+mod array_windows_mut;
+#[cfg(feature = "alloc")]
+mod btree_map_entries;
+#[cfg(feature = "alloc")]
+mod cursors;
+#[cfg(feature = "std")]
+mod hash_map_entries;
 mod into_lending;
 mod lend_refs;
 mod lend_refs_mut;
+#[cfg(feature = "alloc")]
+mod map_entry;
+mod slice_windows_mut;
+mod split_mut;
+mod split_str_mut;
+#[cfg(feature = "alloc")]
 mod windows;
+#[cfg(feature = "alloc")]
 mod windows_mut;
+#[cfg(feature = "alloc")]
+pub use self::array_windows::ArrayWindows;
+#[cfg(feature = "alloc")]
+pub use self::array_windows_mut::ArrayWindowsMut;
+#[cfg(feature = "alloc")]
+pub use self::btree_map_entries::BTreeMapEntries;
+#[cfg(feature = "alloc")]
+pub use self::cursors::{
+    Cursor, CursorCollection, Cursors, IntoCursors, LinkedListCursor, LinkedListCursors, VecCursor,
+    VecCursors, VecDequeCursor, VecDequeCursors,
+};
+#[cfg(feature = "std")]
+pub use self::hash_map_entries::HashMapEntries;
 pub use self::into_lending::IntoLending;
+pub(crate) use self::into_lending::iter_advance_by;
 pub use self::lend_refs::LendRefs;
 pub use self::lend_refs_mut::LendRefsMut;
+#[cfg(feature = "alloc")]
+pub use self::map_entry::MapEntry;
+pub(crate) use self::slice_windows_mut::SliceBuffer;
+pub use self::slice_windows_mut::SliceWindowsMut;
+pub(crate) use self::split_mut::SplitMode;
+pub use self::split_mut::SplitMut;
+pub use self::split_str_mut::SplitStrMut;
+#[cfg(feature = "alloc")]
 pub use self::windows::Windows;
+#[cfg(feature = "alloc")]
+pub(crate) use self::windows::{windows_advance_by, windows_drain, windows_push};
+#[cfg(feature = "alloc")]
 pub use self::windows_mut::WindowsMut;
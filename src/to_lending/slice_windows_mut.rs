@@ -0,0 +1,171 @@
+use core::{fmt, num::NonZeroUsize, ops::DerefMut};
+
+use crate::{ExactSizeLendingIterator, LendingIterator, Resettable};
+
+/// A buffer of elements that derefs to a slice.
+///
+/// Naming the element type through `B` rather than a parameter of its own means
+/// `B: 'a` implies `B::Elem: 'a`, which lending `&'a mut [B::Elem]` needs.
+pub trait SliceBuffer: DerefMut<Target = [Self::Elem]> {
+    /// The type of the elements.
+    type Elem;
+}
+
+impl<B, T> SliceBuffer for B
+where
+    B: DerefMut<Target = [T]>,
+{
+    type Elem = T;
+}
+
+/// A lending iterator over overlapping mutable windows of a buffer of `T`s.
+///
+/// Unlike [`WindowsMut`](crate::WindowsMut), the windows are lent in place, so
+/// changes made through one window are seen by the next. Since the whole buffer
+/// is kept, the lending iterator can [`reset`](Resettable::reset) to the first
+/// window without cloning anything, which [`reset_cycle`] relies on.
+///
+/// The buffer is owned by the lending iterator, and can be taken back with
+/// [`into_inner`](Self::into_inner).
+///
+/// This `struct` is created by the [`lend_windows_mut`] method on
+/// [`ToLendingSliceWindows`]. See its documentation for more.
+///
+/// [`reset_cycle`]: crate::LendingIterator::reset_cycle
+/// [`ToLendingSliceWindows`]: crate::ToLendingSliceWindows
+/// [`lend_windows_mut`]: crate::ToLendingSliceWindows::lend_windows_mut
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SliceWindowsMut<B> {
+    buffer: B,
+    size: usize,
+    // The start of the next window.
+    pos: usize,
+}
+
+impl<B> SliceWindowsMut<B> {
+    pub(crate) fn new(buffer: B, size: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        SliceWindowsMut {
+            buffer,
+            size,
+            pos: 0,
+        }
+    }
+
+    /// Returns the buffer, with any changes made through the lent windows.
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+}
+
+impl<B: SliceBuffer> SliceWindowsMut<B> {
+    fn remaining(&self) -> usize {
+        (self.buffer.len() + 1)
+            .saturating_sub(self.size)
+            .saturating_sub(self.pos)
+    }
+}
+
+impl<B: fmt::Debug> fmt::Debug for SliceWindowsMut<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SliceWindowsMut")
+            .field("buffer", &self.buffer)
+            .field("size", &self.size)
+            .field("pos", &self.pos)
+            .finish()
+    }
+}
+
+impl<B: SliceBuffer> LendingIterator for SliceWindowsMut<B> {
+    type Item<'a> = &'a mut [B::Elem]
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        let window = self.buffer.get_mut(self.pos..self.pos + self.size)?;
+        self.pos += 1;
+        Some(window)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = self.remaining().min(n);
+        self.pos += step;
+        NonZeroUsize::new(n - step).map_or(Ok(()), Err)
+    }
+
+    #[inline]
+    fn fold<Acc, F>(mut self, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &mut [B::Elem]) -> Acc,
+    {
+        let size = self.size;
+        let end = self.pos + self.remaining();
+        (self.pos..end).fold(init, |acc, pos| f(acc, &mut self.buffer[pos..pos + size]))
+    }
+}
+
+impl<B: SliceBuffer> ExactSizeLendingIterator for SliceWindowsMut<B> {}
+
+impl<B: SliceBuffer> Resettable for SliceWindowsMut<B> {
+    #[inline]
+    fn reset(&mut self) {
+        self.pos = 0;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, Resettable, ToLendingSliceWindows};
+
+    #[test]
+    fn in_place() {
+        let mut buffer = [1, 1, 0, 0, 0];
+        let mut windows = buffer[..].lend_windows_mut(3);
+        while let Some(w) = windows.next() {
+            w[2] = w[0] + w[1];
+        }
+        assert_eq!(buffer, [1, 1, 2, 3, 5]);
+        assert_eq!([0; 2][..].lend_windows_mut(3).next(), None);
+    }
+
+    #[test]
+    fn reset() {
+        let mut buffer = [0, 1, 2, 3];
+        let mut windows = buffer[..].lend_windows_mut(2);
+        windows.nth(1).unwrap()[0] = 10;
+        windows.reset();
+        assert_eq!(windows.next(), Some(&mut [0, 10][..]));
+
+        let mut cycle = windows.reset_cycle();
+        assert_eq!(cycle.nth(2), Some(&mut [0, 10][..]));
+        assert_eq!(cycle.size_hint(), (usize::MAX, None));
+
+        let mut buffer = [0, 1, 2, 3];
+        let mut take = buffer[..].lend_windows_mut(2).skip(1).take(2).reset_cycle();
+        let firsts: [i32; 5] = core::array::from_fn(|_| take.next().unwrap()[0]);
+        assert_eq!(firsts, [1, 2, 1, 2, 1]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn contracts() {
+        use crate::testing;
+
+        for size in 1..5 {
+            let make = || vec![0_u8, 1, 2].lend_windows_mut(size);
+            testing::assert_size_hint(make);
+            testing::assert_fused(make);
+            testing::assert_nth(make, |w: &mut [u8]| w.to_vec());
+            testing::assert_exact_size(make);
+        }
+    }
+}
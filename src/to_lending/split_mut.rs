@@ -0,0 +1,217 @@
+use core::{fmt, mem};
+
+use crate::{to_lending::SliceBuffer, LendingIterator};
+
+/// Which of the `split` family a [`SplitMut`] or [`SplitStrMut`](crate::SplitStrMut)
+/// mirrors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SplitMode {
+    /// Like `split`: the delimiters are left out, and every delimiter ends a token.
+    Split,
+    /// Like `split_inclusive`: each token ends with its delimiter, and an empty
+    /// last token is skipped.
+    Inclusive,
+    /// Like `split_terminator`: the delimiters are left out, and an empty last
+    /// token is skipped.
+    Terminator,
+}
+
+impl SplitMode {
+    // The size hint for the tokens of `remaining` units.
+    pub(crate) fn size_hint(self, remaining: usize) -> (usize, Option<usize>) {
+        match self {
+            SplitMode::Split => (1, remaining.checked_add(1)),
+            SplitMode::Inclusive | SplitMode::Terminator => (remaining.min(1), Some(remaining)),
+        }
+    }
+}
+
+/// A lending iterator over the mutable subslices of a buffer of `T`s, separated
+/// by elements that match a predicate.
+///
+/// The buffer is owned by the lending iterator, and can be taken back with
+/// [`into_inner`](Self::into_inner). Owned buffers like `Vec<T>` make it `'static`,
+/// so it can be used with adapters taking closures, like [`filter`](LendingIterator::filter).
+///
+/// This `struct` is created by the [`lend_split_mut`], [`lend_split_inclusive_mut`]
+/// and [`lend_split_terminator_mut`] methods on [`ToLendingSplits`]. See their
+/// documentation for more.
+///
+/// [`ToLendingSplits`]: crate::ToLendingSplits
+/// [`lend_split_mut`]: crate::ToLendingSplits::lend_split_mut
+/// [`lend_split_inclusive_mut`]: crate::ToLendingSplits::lend_split_inclusive_mut
+/// [`lend_split_terminator_mut`]: crate::ToLendingSplits::lend_split_terminator_mut
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitMut<B, P> {
+    buffer: B,
+    // The start of the next token, or `None` once the last one was lent.
+    start: Option<usize>,
+    predicate: P,
+    mode: SplitMode,
+}
+
+impl<B, P> SplitMut<B, P> {
+    pub(crate) fn new(buffer: B, predicate: P, mode: SplitMode) -> Self {
+        SplitMut {
+            buffer,
+            start: Some(0),
+            predicate,
+            mode,
+        }
+    }
+
+    /// Returns the buffer, with any changes made to the lent tokens.
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+}
+
+impl<B: fmt::Debug, P> fmt::Debug for SplitMut<B, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitMut")
+            .field("buffer", &self.buffer)
+            .field("start", &self.start)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B, P> LendingIterator for SplitMut<B, P>
+where
+    B: SliceBuffer,
+    P: FnMut(&B::Elem) -> bool,
+{
+    type Item<'a> = &'a mut [B::Elem]
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        let start = self.start?;
+        let slice: &mut [B::Elem] = &mut self.buffer;
+        let rest = &mut slice[start..];
+        if let Some(i) = rest.iter().position(&mut self.predicate) {
+            self.start = Some(start + i + 1);
+            let end = if self.mode == SplitMode::Inclusive { i + 1 } else { i };
+            Some(&mut rest[..end])
+        } else {
+            self.start = None;
+            if rest.is_empty() && self.mode != SplitMode::Split {
+                None
+            } else {
+                Some(rest)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.start {
+            Some(start) => self.mode.size_hint(self.buffer.len() - start),
+            None => (0, Some(0)),
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, F>(mut self, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &mut [B::Elem]) -> Acc,
+    {
+        let Some(start) = self.start else {
+            return init;
+        };
+        let mut acc = init;
+        let mut rest = &mut self.buffer[start..];
+        while let Some(i) = rest.iter().position(&mut self.predicate) {
+            let (token, tail) = mem::take(&mut rest).split_at_mut(i + 1);
+            let end = if self.mode == SplitMode::Inclusive { i + 1 } else { i };
+            acc = f(acc, &mut token[..end]);
+            rest = tail;
+        }
+        if rest.is_empty() && self.mode != SplitMode::Split {
+            acc
+        } else {
+            f(acc, rest)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use crate::{testing, LendingIterator, ToLendingSplits};
+
+    const INPUTS: [&[u8]; 7] = [b"", b",", b"a", b"a,b", b",a,,b,", b"ab,,", b"a,b,c"];
+
+    // Like with `slice::split`, predicates take the elements by reference.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn comma(b: &u8) -> bool {
+        *b == b','
+    }
+
+    fn tokens<I>(split: I) -> Vec<Vec<u8>>
+    where
+        I: for<'a> LendingIterator<Item<'a> = &'a mut [u8]> + 'static,
+    {
+        split.map(|token: &mut [u8]| token.to_vec()).into_iter().collect()
+    }
+
+    #[test]
+    fn matches_std() {
+        for input in INPUTS {
+            let split: Vec<_> = input.split(comma).map(<[u8]>::to_vec).collect();
+            assert_eq!(tokens(input.to_vec().lend_split_mut(comma)), split);
+            let inclusive: Vec<_> = input.split_inclusive(comma).map(<[u8]>::to_vec).collect();
+            assert_eq!(tokens(input.to_vec().lend_split_inclusive_mut(comma)), inclusive);
+            let terminator: Vec<_> = core::str::from_utf8(input)
+                .unwrap()
+                .split_terminator(',')
+                .map(|token| token.as_bytes().to_vec())
+                .collect();
+            assert_eq!(tokens(input.to_vec().lend_split_terminator_mut(comma)), terminator);
+        }
+    }
+
+    #[test]
+    fn edit_in_place() {
+        let mut buffer = *b"One TWO  three";
+        let mut split = buffer[..].lend_split_mut(|b| *b == b' ').enumerate();
+        while let Some((i, token)) = split.next() {
+            if i % 2 == 0 {
+                token.make_ascii_uppercase();
+            } else {
+                token.make_ascii_lowercase();
+            }
+        }
+        assert_eq!(&buffer, b"ONE two  three");
+    }
+
+    #[test]
+    fn filter() {
+        let split = b",a,,bc,".to_vec().lend_split_mut(comma);
+        let non_empty = split.filter(|token: &&mut [u8]| !token.is_empty());
+        assert_eq!(tokens(non_empty), [b"a".to_vec(), b"bc".to_vec()]);
+    }
+
+    #[test]
+    fn into_inner() {
+        let mut split = b"ab,cd".to_vec().lend_split_inclusive_mut(comma);
+        split.next().unwrap().reverse();
+        assert_eq!(split.into_inner(), b",bacd");
+    }
+
+    #[test]
+    fn contracts() {
+        for input in INPUTS {
+            let make = || input.to_vec().lend_split_mut(comma);
+            testing::assert_size_hint(make);
+            testing::assert_fused(make);
+            testing::assert_nth(make, |token: &mut [u8]| token.to_vec());
+            let make = || input.to_vec().lend_split_inclusive_mut(comma);
+            testing::assert_size_hint(make);
+            testing::assert_fused(make);
+            let make = || input.to_vec().lend_split_terminator_mut(comma);
+            testing::assert_size_hint(make);
+            testing::assert_fused(make);
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use core::{fmt, mem, ops::DerefMut};
+
+use crate::{to_lending::SplitMode, LendingIterator};
+
+/// A lending iterator over the mutable substrings of a string buffer, separated
+/// by characters that match a predicate.
+///
+/// The buffer is owned by the lending iterator, and can be taken back with
+/// [`into_inner`](Self::into_inner). Owned buffers like `String` make it `'static`,
+/// so it can be used with adapters taking closures, like [`filter`](LendingIterator::filter).
+///
+/// This `struct` is created by the [`lend_split_mut`], [`lend_split_inclusive_mut`]
+/// and [`lend_split_terminator_mut`] methods on [`ToLendingStrSplits`]. See their
+/// documentation for more.
+///
+/// [`ToLendingStrSplits`]: crate::ToLendingStrSplits
+/// [`lend_split_mut`]: crate::ToLendingStrSplits::lend_split_mut
+/// [`lend_split_inclusive_mut`]: crate::ToLendingStrSplits::lend_split_inclusive_mut
+/// [`lend_split_terminator_mut`]: crate::ToLendingStrSplits::lend_split_terminator_mut
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitStrMut<S, P> {
+    buffer: S,
+    // The byte offset of the next token, or `None` once the last one was lent.
+    start: Option<usize>,
+    predicate: P,
+    mode: SplitMode,
+}
+
+impl<S, P> SplitStrMut<S, P> {
+    pub(crate) fn new(buffer: S, predicate: P, mode: SplitMode) -> Self {
+        SplitStrMut {
+            buffer,
+            start: Some(0),
+            predicate,
+            mode,
+        }
+    }
+
+    /// Returns the buffer, with any changes made to the lent tokens.
+    pub fn into_inner(self) -> S {
+        self.buffer
+    }
+}
+
+impl<S: fmt::Debug, P> fmt::Debug for SplitStrMut<S, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitStrMut")
+            .field("buffer", &self.buffer)
+            .field("start", &self.start)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, P> LendingIterator for SplitStrMut<S, P>
+where
+    S: DerefMut<Target = str>,
+    P: FnMut(char) -> bool,
+{
+    type Item<'a> = &'a mut str
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        let start = self.start?;
+        let string: &mut str = &mut self.buffer;
+        let rest = &mut string[start..];
+        let predicate = &mut self.predicate;
+        if let Some((i, c)) = rest.char_indices().find(|&(_, c)| predicate(c)) {
+            self.start = Some(start + i + c.len_utf8());
+            let end = if self.mode == SplitMode::Inclusive {
+                i + c.len_utf8()
+            } else {
+                i
+            };
+            Some(&mut rest[..end])
+        } else {
+            self.start = None;
+            if rest.is_empty() && self.mode != SplitMode::Split {
+                None
+            } else {
+                Some(rest)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.start {
+            Some(start) => self.mode.size_hint(self.buffer.len() - start),
+            None => (0, Some(0)),
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, F>(mut self, init: Acc, mut f: F) -> Acc
+    where
+        F: FnMut(Acc, &mut str) -> Acc,
+    {
+        let Some(start) = self.start else {
+            return init;
+        };
+        let mut acc = init;
+        let mut rest = &mut self.buffer[start..];
+        let predicate = &mut self.predicate;
+        while let Some((i, c)) = rest.char_indices().find(|&(_, c)| predicate(c)) {
+            let (token, tail) = mem::take(&mut rest).split_at_mut(i + c.len_utf8());
+            let end = if self.mode == SplitMode::Inclusive { token.len() } else { i };
+            acc = f(acc, &mut token[..end]);
+            rest = tail;
+        }
+        if rest.is_empty() && self.mode != SplitMode::Split {
+            acc
+        } else {
+            f(acc, rest)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use crate::{testing, LendingIterator, ToLendingStrSplits};
+
+    const INPUTS: [&str; 8] = ["", "·", "a", "a·b", "·a··b·", "ab··", "a·b·c", "é·ü"];
+
+    fn dot(c: char) -> bool {
+        c == '·'
+    }
+
+    fn tokens<I>(split: I) -> Vec<String>
+    where
+        I: for<'a> LendingIterator<Item<'a> = &'a mut str> + 'static,
+    {
+        split.map(|token: &mut str| token.to_owned()).into_iter().collect()
+    }
+
+    #[test]
+    fn matches_std() {
+        for input in INPUTS {
+            assert_eq!(
+                tokens(String::from(input).lend_split_mut(dot)),
+                input.split('·').collect::<Vec<_>>()
+            );
+            assert_eq!(
+                tokens(String::from(input).lend_split_inclusive_mut(dot)),
+                input.split_inclusive('·').collect::<Vec<_>>()
+            );
+            assert_eq!(
+                tokens(String::from(input).lend_split_terminator_mut(dot)),
+                input.split_terminator('·').collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn normalize() {
+        let mut text = String::from("Hello, WORLD! Ça va?");
+        let mut words = text.as_mut_str().lend_split_mut(|c| !c.is_alphanumeric()).enumerate();
+        while let Some((i, word)) = words.next() {
+            if i > 0 {
+                word.make_ascii_lowercase();
+            }
+        }
+        assert_eq!(text, "Hello, world! Ça va?");
+    }
+
+    #[test]
+    fn filter() {
+        let words = String::from("Lending  iterators,  lend!")
+            .lend_split_terminator_mut(|c| !c.is_alphabetic())
+            .filter(|word: &&mut str| !word.is_empty());
+        assert_eq!(tokens(words), ["Lending", "iterators", "lend"]);
+    }
+
+    #[test]
+    fn contracts() {
+        for input in INPUTS {
+            let make = || String::from(input).lend_split_mut(dot);
+            testing::assert_size_hint(make);
+            testing::assert_fused(make);
+            testing::assert_nth(make, |token: &mut str| token.to_owned());
+            let make = || String::from(input).lend_split_inclusive_mut(dot);
+            testing::assert_size_hint(make);
+            testing::assert_fused(make);
+            let make = || String::from(input).lend_split_terminator_mut(dot);
+            testing::assert_size_hint(make);
+            testing::assert_fused(make);
+        }
+    }
+}
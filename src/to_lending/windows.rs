@@ -1,4 +1,7 @@
-use crate::LendingIterator;
+use alloc::vec::Vec;
+use core::num::NonZeroUsize;
+
+use crate::{ExactSizeLendingIterator, LendingIterator};
 
 /// A lending iterator over windows.
 ///
@@ -7,18 +10,69 @@ use crate::LendingIterator;
 ///
 /// [`ToLendingIterator`]: crate::ToLendingIterator
 /// [`windows`]: crate::ToLendingIterator::windows
-#[derive(Clone)]
 pub struct Windows<I: Iterator> {
     iter: I,
     size: usize,
     buf: Vec<I::Item>,
 }
 
+impl<I: Iterator + Clone> Clone for Windows<I>
+where
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            size: self.size,
+            buf: self.buf.clone(),
+        }
+    }
+
+    // Reuses the buffer, which matters when cycling.
+    fn clone_from(&mut self, source: &Self) {
+        self.iter.clone_from(&source.iter);
+        self.size = source.size;
+        self.buf.clone_from(&source.buf);
+    }
+}
+
 impl<I: Iterator> Windows<I> {
     pub(crate) fn new(mut iter: I, size: usize) -> Self {
         let buf = iter.by_ref().take(size - 1).collect();
         Self { iter, size, buf }
     }
+
+    /// Consumes the lending iterator, calling a closure on its last window and
+    /// returning the result.
+    ///
+    /// Unlike [`LendingIterator::last_with`], this doesn't need the underlying
+    /// iterator to know its length: it is drained, keeping only the items of the
+    /// last window. For exact-size iterators, `LendingIterator::last_with` skips
+    /// to the last window without looking at the items before it.
+    ///
+    /// ```
+    /// use gat_lending_iterator::ToLendingIterator;
+    ///
+    /// let last = (0..10).filter(|x| x % 3 != 0).windows(2).last_with(|w| w.to_vec());
+    /// assert_eq!(last, Some(vec![7, 8]));
+    /// ```
+    pub fn last_with<B, F>(self, f: F) -> Option<B>
+    where
+        F: FnOnce(&[I::Item]) -> B,
+    {
+        let Self { iter, size, mut buf } = self;
+        windows_drain(iter, &mut buf, size).then(|| f(&buf[buf.len() - size..]))
+    }
+
+    /// Consumes the lending iterator, calling a closure on its last window, if any.
+    ///
+    /// See [`last_with`](Self::last_with).
+    pub fn for_last<F>(self, f: F)
+    where
+        F: FnOnce(&[I::Item]),
+    {
+        self.last_with(f);
+    }
 }
 
 impl<I: Iterator> LendingIterator for Windows<I> {
@@ -28,12 +82,154 @@ impl<I: Iterator> LendingIterator for Windows<I> {
     ;
 
     fn next(&mut self) -> Option<Self::Item<'_>> {
-        self.iter.next().map(|next| {
-            if self.buf.len() == self.size * 2 - 1 {
-                self.buf.drain(..self.size);
+        let next = self.iter.next()?;
+        Some(windows_push(&mut self.buf, self.size, next))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The underlying iterator ran out before the first window was filled.
+        if self.buf.len() + 1 < self.size {
+            return (0, Some(0));
+        }
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        windows_advance_by(&mut self.iter, &mut self.buf, self.size, n)
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &[I::Item]) -> B,
+    {
+        let Self { iter, size, mut buf } = self;
+        iter.fold(init, move |acc, next| f(acc, windows_push(&mut buf, size, next)))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for Windows<I> {}
+
+/// Pushes `next` to the buffer of a lending iterator over windows of `size`, and
+/// returns the window it ends.
+///
+/// The buffer holds up to `size * 2 - 1` items, so the items of a window are
+/// moved to its start once every `size` pushes rather than on every push.
+pub(crate) fn windows_push<T>(buf: &mut Vec<T>, size: usize, next: T) -> &mut [T] {
+    if buf.len() == size * 2 - 1 {
+        buf.drain(..size);
+    }
+    buf.push(next);
+    let range = buf.len() - size..;
+    &mut buf[range]
+}
+
+/// Drains `iter` into the buffer of a lending iterator over windows of `size`,
+/// keeping the items of the last window at its end.
+///
+/// Returns whether there was a window left.
+pub(crate) fn windows_drain<I: Iterator>(iter: I, buf: &mut Vec<I::Item>, size: usize) -> bool {
+    let mut any = false;
+    iter.for_each(|next| {
+        any = true;
+        windows_push(buf, size, next);
+    });
+    any
+}
+
+/// Advances a lending iterator over windows of `size` by `n` windows.
+///
+/// Only the last `size - 1` skipped items are needed for the next window, so the
+/// ones before them that `iter` is known to have are skipped without being buffered.
+pub(crate) fn windows_advance_by<I: Iterator>(
+    iter: &mut I,
+    buf: &mut Vec<I::Item>,
+    size: usize,
+    n: usize,
+) -> Result<(), NonZeroUsize> {
+    let keep = size - 1;
+    let mut skipped = 0;
+    let lower = iter.size_hint().0.min(n);
+    if lower > keep {
+        buf.clear();
+        if iter.nth(lower - keep - 1).is_none() {
+            // The lower bound was wrong, so how many items were skipped is unknown.
+            return NonZeroUsize::new(n).map_or(Ok(()), Err);
+        }
+        buf.extend(iter.by_ref().take(keep));
+        skipped = lower - keep + buf.len();
+    }
+    for i in skipped..n {
+        match iter.next() {
+            Some(next) => {
+                windows_push(buf, size, next);
+            }
+            // `i` is always less than `n`, so this is always `Err`.
+            None => return NonZeroUsize::new(n - i).map_or(Ok(()), Err),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{testing, LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn nth() {
+        let nums: Vec<i32> = (0..10).collect();
+        for size in 1..4 {
+            for n in 0..12 {
+                for skip in 0..3 {
+                    let std = nums[..].windows(size).skip(skip).nth(n);
+                    // An exact-size source takes the fast path, a filtered one doesn't.
+                    let mut exact = (0..10).windows(size);
+                    let mut inexact = (0..10).filter(|_| true).windows(size);
+                    exact.advance_by(skip).ok();
+                    inexact.advance_by(skip).ok();
+                    assert_eq!(exact.nth(n), std);
+                    assert_eq!(inexact.nth(n), std);
+                    assert_eq!(exact.next(), nums[..].windows(size).nth(skip + n + 1));
+                }
             }
-            self.buf.push(next);
-            &self.buf[self.buf.len() - self.size..]
-        })
+        }
+        assert_eq!((0..5).windows(2).advance_by(6).map_err(usize::from), Err(2));
+    }
+
+    // Claims 10 more items than it has.
+    struct Overstated<I>(I);
+
+    impl<I: Iterator> Iterator for Overstated<I> {
+        type Item = I::Item;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.0.next()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.0.size_hint().0 + 10, None)
+        }
+    }
+
+    #[test]
+    fn overstated_lower_bound() {
+        // `nth` runs out, so how many windows were skipped is unknown.
+        let mut windows = Overstated(0..5).windows(2);
+        assert_eq!(windows.advance_by(6).map_err(usize::from), Err(6));
+        assert_eq!(windows.next(), None);
+        // `nth` succeeds, but none of the items kept for the next window are left.
+        let mut windows = Overstated(0..12).windows(3);
+        assert_eq!(windows.advance_by(12).map_err(usize::from), Err(2));
+        assert_eq!(windows.next(), None);
+    }
+
+    #[test]
+    fn lower_bound() {
+        // Only the first 6 items are known to exist, so the rest are skipped one at a time.
+        let make = || (0..6).chain((6..10).filter(|_| true)).windows(3);
+        testing::assert_nth(make, |w: &[i32]| w.to_vec());
+        testing::assert_size_hint(make);
     }
 }
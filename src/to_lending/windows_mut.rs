@@ -1,4 +1,7 @@
-use crate::LendingIterator;
+use alloc::vec::Vec;
+use core::num::NonZeroUsize;
+
+use crate::{windows_advance_by, windows_drain, windows_push, ExactSizeLendingIterator, LendingIterator};
 
 /// A lending iterator over mutable windows.
 ///
@@ -7,18 +10,63 @@ use crate::LendingIterator;
 ///
 /// [`ToLendingIterator`]: crate::ToLendingIterator
 /// [`windows_mut`]: crate::ToLendingIterator::windows_mut
-#[derive(Clone)]
 pub struct WindowsMut<I: Iterator> {
     iter: I,
     size: usize,
     buf: Vec<I::Item>,
 }
 
+impl<I: Iterator + Clone> Clone for WindowsMut<I>
+where
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            size: self.size,
+            buf: self.buf.clone(),
+        }
+    }
+
+    // Reuses the buffer, which matters when cycling.
+    fn clone_from(&mut self, source: &Self) {
+        self.iter.clone_from(&source.iter);
+        self.size = source.size;
+        self.buf.clone_from(&source.buf);
+    }
+}
+
 impl<I: Iterator> WindowsMut<I> {
     pub(crate) fn new(mut iter: I, size: usize) -> Self {
         let buf = iter.by_ref().take(size - 1).collect();
         Self { iter, size, buf }
     }
+
+    /// Consumes the lending iterator, calling a closure on its last window and
+    /// returning the result.
+    ///
+    /// Like [`Windows::last_with`](crate::Windows::last_with), this drains the
+    /// underlying iterator, so it doesn't need to know its length.
+    pub fn last_with<B, F>(self, f: F) -> Option<B>
+    where
+        F: FnOnce(&mut [I::Item]) -> B,
+    {
+        let Self { iter, size, mut buf } = self;
+        windows_drain(iter, &mut buf, size).then(|| {
+            let range = buf.len() - size..;
+            f(&mut buf[range])
+        })
+    }
+
+    /// Consumes the lending iterator, calling a closure on its last window, if any.
+    ///
+    /// See [`last_with`](Self::last_with).
+    pub fn for_last<F>(self, f: F)
+    where
+        F: FnOnce(&mut [I::Item]),
+    {
+        self.last_with(f);
+    }
 }
 
 impl<I: Iterator> LendingIterator for WindowsMut<I> {
@@ -28,13 +76,32 @@ impl<I: Iterator> LendingIterator for WindowsMut<I> {
     ;
 
     fn next(&mut self) -> Option<Self::Item<'_>> {
-        self.iter.next().map(|next| {
-            if self.buf.len() == self.size * 2 - 1 {
-                self.buf.drain(..self.size);
-            }
-            self.buf.push(next);
-            let range = self.buf.len() - self.size..;
-            &mut self.buf[range]
-        })
+        let next = self.iter.next()?;
+        Some(windows_push(&mut self.buf, self.size, next))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The underlying iterator ran out before the first window was filled.
+        if self.buf.len() + 1 < self.size {
+            return (0, Some(0));
+        }
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        windows_advance_by(&mut self.iter, &mut self.buf, self.size, n)
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &mut [I::Item]) -> B,
+    {
+        let Self { iter, size, mut buf } = self;
+        iter.fold(init, move |acc, next| f(acc, windows_push(&mut buf, size, next)))
     }
 }
+
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for WindowsMut<I> {}
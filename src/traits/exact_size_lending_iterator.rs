@@ -0,0 +1,88 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use crate::LendingIterator;
+
+/// A lending iterator that knows its exact length.
+///
+/// Since a lending iterator can't peek at its next item, some adapters, such as
+/// [`intersperse`], rely on the length to know whether more items will follow.
+///
+/// See [`ExactSizeIterator`].
+///
+/// [`intersperse`]: crate::LendingIterator::intersperse
+pub trait ExactSizeLendingIterator: LendingIterator {
+    /// Returns the exact remaining length of the lending iterator.
+    ///
+    /// See [`ExactSizeIterator::len`].
+    ///
+    /// # Panics
+    ///
+    /// The default implementation panics if [`size_hint`] doesn't return
+    /// an exact length.
+    ///
+    /// [`size_hint`]: LendingIterator::size_hint
+    #[inline]
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        assert_eq!(upper, Some(lower));
+        lower
+    }
+
+    /// Returns `true` if the lending iterator is empty.
+    ///
+    /// See [`ExactSizeIterator::is_empty`].
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<I: ExactSizeLendingIterator + ?Sized> ExactSizeLendingIterator for &mut I {
+    #[inline]
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ExactSizeLendingIterator + ?Sized> ExactSizeLendingIterator for Box<I> {
+    #[inline]
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use crate::{ExactSizeLendingIterator, LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn lengths() {
+        fn first(w: &[i32]) -> i32 {
+            w[0]
+        }
+        let nums: Vec<i32> = (0..10).collect();
+        for size in 1..4 {
+            let windows = || (0..10).windows(size);
+            let std = || nums[..].windows(size);
+            assert_eq!(windows().len(), std().len());
+            assert_eq!(windows().map(first).len(), std().len());
+            assert_eq!(windows().enumerate().len(), std().len());
+            assert_eq!(windows().take(3).len(), std().take(3).len());
+            assert_eq!(windows().skip(3).len(), std().skip(3).len());
+            assert_eq!(windows().zip((0..4).into_lending()).len(), 4);
+            for step in 1..4 {
+                let mut step_by = windows().step_by(step);
+                let mut std = std().step_by(step);
+                while step_by.next().is_some() {
+                    std.next();
+                    assert_eq!(step_by.len(), std.len());
+                }
+            }
+        }
+        assert!((0..1).windows(2).is_empty());
+        assert_eq!((0..4).windows(2).chain((0..4).windows(2)).size_hint(), (6, Some(6)));
+        assert_eq!((0..4).windows(2).filter(|w| w[0] > 0).size_hint(), (0, Some(3)));
+    }
+}
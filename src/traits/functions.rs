@@ -46,3 +46,69 @@ impl<T> OptionTrait for Option<T> {
         self
     }
 }
+
+/// Used in cases that a lent item needs to be converted into a value
+/// that doesn't borrow from the lending iterator.
+///
+/// References are converted with [`ToOwned`], and pairs convert both of their halves,
+/// so the items of [`Zip`] and [`Enumerate`] can be converted as well.
+///
+/// [`ToOwned`]: alloc::borrow::ToOwned
+/// [`Zip`]: crate::Zip
+/// [`Enumerate`]: crate::Enumerate
+pub trait IntoOwned {
+    /// The type of the owned value.
+    type Owned;
+
+    /// Converts `self` into an owned value.
+    fn into_owned(self) -> Self::Owned;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: alloc::borrow::ToOwned + ?Sized> IntoOwned for &T {
+    type Owned = T::Owned;
+
+    fn into_owned(self) -> Self::Owned {
+        self.to_owned()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: alloc::borrow::ToOwned + ?Sized> IntoOwned for &mut T {
+    type Owned = T::Owned;
+
+    fn into_owned(self) -> Self::Owned {
+        (*self).to_owned()
+    }
+}
+
+impl<A: IntoOwned, B: IntoOwned> IntoOwned for (A, B) {
+    type Owned = (A::Owned, B::Owned);
+
+    fn into_owned(self) -> Self::Owned {
+        (self.0.into_owned(), self.1.into_owned())
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Option<T> {
+    type Owned = Option<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        self.map(T::into_owned)
+    }
+}
+
+macro_rules! impl_into_owned {
+    ($($t:ty)*) => {$(
+        impl IntoOwned for $t {
+            type Owned = $t;
+
+            #[inline]
+            fn into_owned(self) -> Self::Owned {
+                self
+            }
+        }
+    )*};
+}
+
+impl_into_owned!(() bool char i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
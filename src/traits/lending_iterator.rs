@@ -1,8 +1,20 @@
-use core::cmp::Ordering;
-use std::{num::NonZeroUsize, ops::Deref};
+use core::{
+    cmp::Ordering,
+    num::NonZeroUsize,
+    borrow::Borrow,
+    ops::{Deref, DerefMut},
+    pin::Pin,
+};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::Owned;
 
 use crate::{
-    Chain, Cloned, Enumerate, Filter, FilterMap, Map, OptionTrait, SingleArgFnMut, SingleArgFnOnce, Skip, SkipWhile, StepBy, Take, TakeWhile, Zip
+    filter_next, ByRef, Chain, ChainSame, Cloned, Copied, Cycle, Enumerate, ExactSizeLendingIterator, Filter, FilterMap, Intersperse, IntersperseWith, IntoIterOwned, IntoOwned, Map, OptionTrait, ProductLending, ResetCycle, Resettable, SingleArgFnMut, SingleArgFnOnce, Skip, SkipWhile, StepBy, SumLending, Take, TakeWhile, Zip
 };
 
 /// Like [`Iterator`], but items may borrow from `&mut self`.
@@ -48,8 +60,8 @@ pub trait LendingIterator {
     fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
         for i in 0..n {
             if self.next().is_none() {
-                // SAFETY: `i` is always less than `n`.
-                return Err(unsafe { NonZeroUsize::new_unchecked(n - i) });
+                // `i` is always less than `n`, so this is always `Err`.
+                return NonZeroUsize::new(n - i).map_or(Ok(()), Err);
             }
         }
         Ok(())
@@ -105,6 +117,10 @@ pub trait LendingIterator {
 
     /// Takes two lending iterators and creates a new lending iterator over both in sequence.
     ///
+    /// Both lending iterators currently need to be `'static`, see [`Chain`] for the details
+    /// and a workaround. Lending iterators of the same type can be chained with
+    /// [`chain_same`](Self::chain_same) instead.
+    ///
     /// See [`Iterator::chain`].
     #[inline]
     fn chain<I>(self, other: I) -> Chain<Self, I>
@@ -115,6 +131,30 @@ pub trait LendingIterator {
         Chain::new(self, other)
     }
 
+    /// Takes two lending iterators of the same type and creates a new lending iterator
+    /// over both in sequence.
+    ///
+    /// Unlike [`chain`](Self::chain), the lending iterators don't need to be `'static`:
+    ///
+    /// ```
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let mut buffer = [1, 2, 3, 4, 5];
+    /// let (left, right) = buffer.split_at_mut(2);
+    /// let mut chain = left.iter_mut().lend_refs_mut().chain_same(right.iter_mut().lend_refs_mut());
+    /// while let Some(x) = chain.next() {
+    ///     **x *= 10;
+    /// }
+    /// assert_eq!(buffer, [10, 20, 30, 40, 50]);
+    /// ```
+    #[inline]
+    fn chain_same(self, other: Self) -> ChainSame<Self>
+    where
+        Self: Sized,
+    {
+        ChainSame::new(self, other)
+    }
+
     /// 'Zips up' two lending iterators into a single lending iterator of pairs.
     #[inline]
     fn zip<I>(self, other: I) -> Zip<Self, I>
@@ -125,6 +165,103 @@ pub trait LendingIterator {
         Zip::new(self, other)
     }
 
+    /// Creates a lending iterator which lends a reference to `separator`
+    /// between adjacent elements.
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let mut out = String::new();
+    /// (0..4).windows(2).intersperse([-1]).for_each(|w: &[i32]| {
+    ///     out.push_str(&format!("{w:?}"));
+    /// });
+    /// assert_eq!(out, "[0, 1][-1][1, 2][-1][2, 3]");
+    /// # }
+    /// ```
+    ///
+    /// # Limitations
+    ///
+    /// The separator is lent in place of an element, so this is only available
+    /// when the elements are shared references `&'a T` to a `'static` type `T`,
+    /// like the `&[i32]` of [`windows`]. Mutable references, like the windows of
+    /// [`windows_mut`], and owned elements can't be interspersed.
+    ///
+    /// A lending iterator can't peek at its next element, so this relies on
+    /// [`ExactSizeLendingIterator`] to know whether another element follows. This
+    /// rules out sources of unknown length, such as [`filter`] or windows over a
+    /// filtered iterator.
+    ///
+    /// Like [`chain`], this also requires `Self: 'static` for now.
+    ///
+    /// See [`Iterator::intersperse`].
+    ///
+    /// [`chain`]: LendingIterator::chain
+    /// [`filter`]: LendingIterator::filter
+    /// [`windows`]: crate::ToLendingIterator::windows
+    /// [`windows_mut`]: crate::ToLendingIterator::windows_mut
+    /// [`Iterator::intersperse`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.intersperse
+    #[inline]
+    fn intersperse<T, S>(self, separator: S) -> Intersperse<Self, S>
+    where
+        Self: Sized,
+        for<'a> Self: ExactSizeLendingIterator + LendingIterator<Item<'a> = &'a T> + 'a,
+        S: Borrow<T>,
+        T: ?Sized + 'static,
+    {
+        Intersperse::new(self, separator)
+    }
+
+    /// Creates a lending iterator which lends a reference to a separator generated by `f`
+    /// between adjacent elements.
+    ///
+    /// The separator is kept in the adapter until the next one is generated.
+    /// It has the same limitations as [`intersperse`](LendingIterator::intersperse).
+    ///
+    /// See [`Iterator::intersperse_with`].
+    ///
+    /// [`Iterator::intersperse_with`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.intersperse_with
+    #[inline]
+    fn intersperse_with<T, S, F>(self, f: F) -> IntersperseWith<Self, F, S>
+    where
+        Self: Sized,
+        for<'a> Self: ExactSizeLendingIterator + LendingIterator<Item<'a> = &'a T> + 'a,
+        F: FnMut() -> S,
+        S: Borrow<T>,
+        T: ?Sized + 'static,
+    {
+        IntersperseWith::new(self, f)
+    }
+
+    /// Repeats a lending iterator endlessly, by cloning it whenever it runs out.
+    ///
+    /// The clone is made with [`Clone::clone_from`], so sources like [`Windows`]
+    /// reuse their buffers.
+    ///
+    /// See [`Iterator::cycle`], and [`reset_cycle`] for lending iterators that can
+    /// rewind without being cloned.
+    ///
+    /// [`Windows`]: crate::Windows
+    /// [`reset_cycle`]: LendingIterator::reset_cycle
+    #[inline]
+    fn cycle(self) -> Cycle<Self>
+    where
+        Self: Sized + Clone,
+    {
+        Cycle::new(self)
+    }
+
+    /// Repeats a lending iterator endlessly, by [`reset`]ting it whenever it runs out.
+    ///
+    /// [`reset`]: Resettable::reset
+    #[inline]
+    fn reset_cycle(self) -> ResetCycle<Self>
+    where
+        Self: Sized + Resettable,
+    {
+        ResetCycle::new(self)
+    }
+
     /// Takes a closure and creates a lending iterator which calls that closure on each
     /// element.
     ///
@@ -151,14 +288,12 @@ pub trait LendingIterator {
     ///
     /// See [`Iterator::for_each`].
     #[inline]
-    fn for_each<F>(mut self, mut f: F)
+    fn for_each<F>(self, mut f: F)
     where
         Self: Sized,
         F: FnMut(Self::Item<'_>),
     {
-        while let Some(item) = self.next() {
-            f(item);
-        }
+        self.fold((), move |(), item| f(item));
     }
 
     /// Creates a lending iterator which uses a closure to determine if an element
@@ -190,7 +325,16 @@ pub trait LendingIterator {
     /// Folds every element into an accumulator by applying an operation,
     /// returning the final result.
     ///
+    /// The sources and adapters of this crate override this to fold the underlying
+    /// iterator or lending iterator where they can, except for:
+    /// - [`Zip`] and [`TakeWhile`], which have to stop partway through the underlying
+    ///   lending iterators, and a fold can't stop early.
+    /// - [`Cursors`] and the map entry lending iterators, which put each element back
+    ///   before lending the next, which is all [`next`](Self::next) does.
+    ///
     /// See [`Iterator::fold`].
+    ///
+    /// [`Cursors`]: crate::Cursors
     #[inline]
     fn fold<B, F>(mut self, init: B, mut f: F) -> B
     where
@@ -204,6 +348,373 @@ pub trait LendingIterator {
         accum
     }
 
+    /// Sums the elements of the lending iterator.
+    ///
+    /// Takes each element, adds them together, and returns the result.
+    /// An empty iterator returns the zero value of the type.
+    ///
+    /// See [`Iterator::sum`] and [`SumLending`].
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// fn first(w: &[i32]) -> &i32 {
+    ///     &w[0]
+    /// }
+    ///
+    /// let sum: i32 = (1..5).windows(2).map(first).sum();
+    /// assert_eq!(sum, 6);
+    /// # }
+    /// ```
+    #[inline]
+    fn sum<S>(mut self) -> S
+    where
+        Self: Sized,
+        S: for<'a> SumLending<Self::Item<'a>>,
+    {
+        let mut sum = <S as SumLending<Self::Item<'_>>>::zero();
+        while let Some(item) = self.next() {
+            sum = sum.add_lent(item);
+        }
+        sum
+    }
+
+    /// Multiplies the elements of the lending iterator.
+    ///
+    /// An empty iterator returns the one value of the type.
+    ///
+    /// See [`Iterator::product`] and [`ProductLending`].
+    #[inline]
+    fn product<P>(mut self) -> P
+    where
+        Self: Sized,
+        P: for<'a> ProductLending<Self::Item<'a>>,
+    {
+        let mut product = <P as ProductLending<Self::Item<'_>>>::one();
+        while let Some(item) = self.next() {
+            product = product.mul_lent(item);
+        }
+        product
+    }
+
+    /// Returns the maximum key computed by `f` over the elements of the lending iterator.
+    ///
+    /// Unlike [`Iterator::max_by_key`], this returns the key rather than the element,
+    /// since a lent element can't be kept while looking at the next one.
+    #[inline]
+    fn max_by_key<B, F>(self, mut f: F) -> Option<B>
+    where
+        Self: Sized,
+        B: Ord,
+        F: FnMut(&Self::Item<'_>) -> B,
+    {
+        self.fold(None, |max, item| {
+            let key = f(&item);
+            match max {
+                Some(max) if max > key => Some(max),
+                _ => Some(key),
+            }
+        })
+    }
+
+    /// Returns the minimum key computed by `f` over the elements of the lending iterator.
+    ///
+    /// Unlike [`Iterator::min_by_key`], this returns the key rather than the element,
+    /// since a lent element can't be kept while looking at the next one.
+    #[inline]
+    fn min_by_key<B, F>(self, mut f: F) -> Option<B>
+    where
+        Self: Sized,
+        B: Ord,
+        F: FnMut(&Self::Item<'_>) -> B,
+    {
+        self.fold(None, |min, item| {
+            let key = f(&item);
+            match min {
+                Some(min) if min <= key => Some(min),
+                _ => Some(key),
+            }
+        })
+    }
+
+    /// Consumes the lending iterator, returning an owned copy of the last element.
+    ///
+    /// Unlike [`Iterator::last`], this converts every element with [`ToOwned`],
+    /// since a lent element can't be kept while looking at the next one.
+    ///
+    /// ```
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// assert_eq!((0..5).windows(2).last_owned(), Some(vec![3, 4]));
+    /// ```
+    ///
+    /// [`ToOwned`]: alloc::borrow::ToOwned
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn last_owned<T>(self) -> Option<T::Owned>
+    where
+        Self: Sized,
+        for<'a> Self::Item<'a>: Deref<Target = T>,
+        T: alloc::borrow::ToOwned + ?Sized,
+    {
+        self.fold(None, |_, item| Some(item.to_owned()))
+    }
+
+    /// Consumes the lending iterator, calling a closure on its last element
+    /// and returning the result.
+    ///
+    /// This is the lending counterpart of [`Iterator::last`]: the last element borrows
+    /// from the lending iterator, so it can only be used before the iterator is dropped.
+    /// Since a lending iterator can't look ahead, this needs to know how many elements
+    /// are left; the ones before the last are skipped with [`nth`], which sources like
+    /// [`Windows`] implement without buffering every element.
+    ///
+    /// [`Windows`] and [`WindowsMut`] also have their own `last_with`, which
+    /// works on sources of any length.
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let last = (0..1000).windows(3).enumerate().last_with(|(i, w)| (i, w.iter().sum::<i32>()));
+    /// assert_eq!(last, Some((997, 997 + 998 + 999)));
+    /// # }
+    /// ```
+    ///
+    /// [`nth`]: LendingIterator::nth
+    /// [`Windows`]: crate::Windows
+    /// [`WindowsMut`]: crate::WindowsMut
+    #[inline]
+    fn last_with<B, F>(mut self, f: F) -> Option<B>
+    where
+        Self: Sized + ExactSizeLendingIterator,
+        F: FnOnce(Self::Item<'_>) -> B,
+    {
+        let len = self.len();
+        self.nth(len.checked_sub(1)?).map(f)
+    }
+
+    /// Consumes the lending iterator, calling a closure on its last element, if any.
+    ///
+    /// See [`last_with`](LendingIterator::last_with).
+    #[inline]
+    fn for_last<F>(self, f: F)
+    where
+        Self: Sized + ExactSizeLendingIterator,
+        F: FnOnce(Self::Item<'_>),
+    {
+        self.last_with(f);
+    }
+
+    /// Consumes the lending iterator, converting each element with [`IntoOwned`] into
+    /// one of two collections.
+    ///
+    /// The first collection contains the elements for which `predicate` returned `true`,
+    /// the second the ones for which it returned `false`.
+    ///
+    /// See [`Iterator::partition`].
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let (even, odd): (Vec<_>, Vec<_>) = (0..5).windows(2).partition_owned(|w| w[0] % 2 == 0);
+    /// assert_eq!(even, vec![vec![0, 1], vec![2, 3]]);
+    /// assert_eq!(odd, vec![vec![1, 2], vec![3, 4]]);
+    /// # }
+    /// ```
+    fn partition_owned<T, B, F>(mut self, mut predicate: F) -> (B, B)
+    where
+        Self: Sized,
+        for<'a> Self::Item<'a>: IntoOwned<Owned = T>,
+        B: Default + Extend<T>,
+        F: FnMut(&Self::Item<'_>) -> bool,
+    {
+        let mut left = B::default();
+        let mut right = B::default();
+        while let Some(item) = self.next() {
+            if predicate(&item) {
+                left.extend(Some(item.into_owned()));
+            } else {
+                right.extend(Some(item.into_owned()));
+            }
+        }
+        (left, right)
+    }
+
+    /// Converts a lending iterator of pairs with [`IntoOwned`] into a pair of collections.
+    ///
+    /// This works for the items of [`zip`] and [`enumerate`], among others.
+    ///
+    /// See [`Iterator::unzip`].
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let (indices, windows): (Vec<_>, Vec<_>) = (0..4).windows(3).enumerate().unzip_owned();
+    /// assert_eq!(indices, vec![0, 1]);
+    /// assert_eq!(windows, vec![vec![0, 1, 2], vec![1, 2, 3]]);
+    /// # }
+    /// ```
+    ///
+    /// [`zip`]: LendingIterator::zip
+    /// [`enumerate`]: LendingIterator::enumerate
+    fn unzip_owned<A, B, FromA, FromB>(mut self) -> (FromA, FromB)
+    where
+        Self: Sized,
+        for<'a> Self::Item<'a>: IntoOwned<Owned = (A, B)>,
+        FromA: Default + Extend<A>,
+        FromB: Default + Extend<B>,
+    {
+        let mut left = FromA::default();
+        let mut right = FromB::default();
+        while let Some(item) = self.next() {
+            let (a, b) = item.into_owned();
+            left.extend(Some(a));
+            right.extend(Some(b));
+        }
+        (left, right)
+    }
+
+    /// Converts each element into an owned value with [`IntoOwned`], and calls `f` on them
+    /// from a pool of scoped worker threads, one per available core.
+    ///
+    /// The lending iterator is driven on the calling thread, and the owned values are
+    /// handed to the workers through a bounded channel, so only a few of them exist at once.
+    /// `f` is called in no particular order; see [`par_map_owned`] to keep it.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the panic is propagated once the workers have stopped.
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicI32, Ordering};
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let total = AtomicI32::new(0);
+    /// (0..100).windows(3).par_for_each_owned(|w: Vec<i32>| {
+    ///     total.fetch_add(w.iter().product(), Ordering::Relaxed);
+    /// });
+    /// assert_eq!(total.into_inner(), (0..98).map(|i| i * (i + 1) * (i + 2)).sum());
+    /// ```
+    ///
+    /// [`par_map_owned`]: LendingIterator::par_map_owned
+    #[cfg(feature = "std")]
+    #[inline]
+    fn par_for_each_owned<T, F>(self, f: F)
+    where
+        Self: Sized,
+        for<'a> Self::Item<'a>: IntoOwned<Owned = T>,
+        T: Send,
+        F: Fn(T) + Sync,
+    {
+        self.par_for_each_owned_with(|item| item.into_owned(), f);
+    }
+
+    /// Like [`par_for_each_owned`], but converts each element with `to_owned`.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the panic is propagated once the workers have stopped.
+    ///
+    /// [`par_for_each_owned`]: LendingIterator::par_for_each_owned
+    #[cfg(feature = "std")]
+    #[inline]
+    fn par_for_each_owned_with<T, C, F>(mut self, mut to_owned: C, f: F)
+    where
+        Self: Sized,
+        C: FnMut(Self::Item<'_>) -> T,
+        T: Send,
+        F: Fn(T) + Sync,
+    {
+        crate::traits::par::for_each(|| self.next().map(&mut to_owned), f);
+    }
+
+    /// Converts each element into an owned value with [`IntoOwned`], and maps them with `f`
+    /// on a pool of scoped worker threads, returning the results in order.
+    ///
+    /// See [`par_for_each_owned`].
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the panic is propagated once the workers have stopped.
+    ///
+    /// ```
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let sums = (0..5).windows(2).par_map_owned(|w: Vec<i32>| w.iter().sum::<i32>());
+    /// assert_eq!(sums, vec![1, 3, 5, 7]);
+    /// ```
+    ///
+    /// [`par_for_each_owned`]: LendingIterator::par_for_each_owned
+    #[cfg(feature = "std")]
+    #[inline]
+    fn par_map_owned<T, R, F>(self, f: F) -> Vec<R>
+    where
+        Self: Sized,
+        for<'a> Self::Item<'a>: IntoOwned<Owned = T>,
+        T: Send,
+        R: Send,
+        F: Fn(T) -> R + Sync,
+    {
+        self.par_map_owned_with(|item| item.into_owned(), f)
+    }
+
+    /// Like [`par_map_owned`], but converts each element with `to_owned`.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the panic is propagated once the workers have stopped.
+    ///
+    /// [`par_map_owned`]: LendingIterator::par_map_owned
+    #[cfg(feature = "std")]
+    #[inline]
+    fn par_map_owned_with<T, R, C, F>(mut self, mut to_owned: C, f: F) -> Vec<R>
+    where
+        Self: Sized,
+        C: FnMut(Self::Item<'_>) -> T,
+        T: Send,
+        R: Send,
+        F: Fn(T) -> R + Sync,
+    {
+        crate::traits::par::map(|| self.next().map(&mut to_owned), f)
+    }
+
+    /// Turns a lending iterator whose items don't borrow from it back into an [`Iterator`].
+    ///
+    /// This allows mixing lending and [`Iterator`] adapters, for example to [`sum`]
+    /// the result of a [`filter`] over an [`IntoLending`].
+    ///
+    /// ```
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let sum: usize = (0..10)
+    ///     .into_lending()
+    ///     .filter(|x: &usize| x % 2 == 0)
+    ///     .into_iter_owned()
+    ///     .sum();
+    /// assert_eq!(sum, 20);
+    /// ```
+    ///
+    /// Like [`chain`], this requires `Self: 'static` for now,
+    /// so iterators over borrowed data have to be converted with [`map`] and
+    /// [`IntoIterator`] instead.
+    ///
+    /// [`sum`]: Iterator::sum
+    /// [`filter`]: LendingIterator::filter
+    /// [`map`]: LendingIterator::map
+    /// [`chain`]: LendingIterator::chain
+    /// [`IntoLending`]: crate::IntoLending
+    fn into_iter_owned<T>(self) -> IntoIterOwned<Self, T>
+    where
+        Self: Sized,
+        for<'a> Self: LendingIterator<Item<'a> = T> + 'a,
+    {
+        IntoIterOwned::new(self)
+    }
+
     /// Creates a lending iterator which [`clone`]s all of its elements.
     ///
     /// The resulting lending iterator implements [`IntoIterator`].
@@ -220,6 +731,47 @@ pub trait LendingIterator {
         Cloned::new(self)
     }
 
+    /// Creates a lending iterator which copies all of its elements.
+    ///
+    /// The resulting lending iterator implements [`IntoIterator`].
+    ///
+    /// See [`Iterator::copied`].
+    fn copied<T>(self) -> Copied<Self>
+    where
+        Self: Sized,
+        for<'a> Self::Item<'a>: Deref<Target = T>,
+        T: Copy,
+    {
+        Copied::new(self)
+    }
+
+    /// Creates a lending iterator which converts all of its elements to owned values
+    /// with [`ToOwned`], for example `&[T]` to `Vec<T>` and `&str` to `String`.
+    ///
+    /// Unlike [`cloned`], this works for unsized referents, such as the slices
+    /// lent by [`windows`].
+    /// The resulting lending iterator implements [`IntoIterator`].
+    ///
+    /// ```
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let windows: Vec<Vec<i32>> = (0..4).windows(3).owned().into_iter().collect();
+    /// assert_eq!(windows, vec![vec![0, 1, 2], vec![1, 2, 3]]);
+    /// ```
+    ///
+    /// [`cloned`]: LendingIterator::cloned
+    /// [`windows`]: crate::ToLendingIterator::windows
+    /// [`ToOwned`]: alloc::borrow::ToOwned
+    #[cfg(feature = "alloc")]
+    fn owned<T>(self) -> Owned<Self>
+    where
+        Self: Sized,
+        for<'a> Self::Item<'a>: Deref<Target = T>,
+        T: alloc::borrow::ToOwned + ?Sized,
+    {
+        Owned::new(self)
+    }
+
     /// Creates a lending iterator which gives the current iteration count as well as the next value.
     #[inline]
     fn enumerate(self) -> Enumerate<Self>
@@ -255,6 +807,14 @@ pub trait LendingIterator {
     /// 
     /// Unfortunately adapters that take in a closure are currently
     /// incompatible with this, due to limitations in the borrow checker.
+    /// Use [`filter_by_ref`], [`filter_map_by_ref`], [`map_by_ref`],
+    /// [`skip_while_by_ref`] or [`take_while_by_ref`] instead.
+    ///
+    /// [`filter_by_ref`]: Self::filter_by_ref
+    /// [`filter_map_by_ref`]: Self::filter_map_by_ref
+    /// [`map_by_ref`]: Self::map_by_ref
+    /// [`skip_while_by_ref`]: Self::skip_while_by_ref
+    /// [`take_while_by_ref`]: Self::take_while_by_ref
     #[inline]
     fn by_ref(&mut self) -> &mut Self
     where
@@ -263,6 +823,70 @@ pub trait LendingIterator {
         self
     }
 
+    /// Like [`filter`](Self::filter), but borrows the lending iterator
+    /// so that it can be resumed afterwards.
+    ///
+    /// See [`ByRef`] for why this is needed instead of `by_ref().filter(predicate)`.
+    #[inline]
+    fn filter_by_ref<P>(&mut self, predicate: P) -> Filter<ByRef<'_, Self>, P>
+    where
+        P: for<'a> FnMut(&Self::Item<'a>) -> bool,
+    {
+        Filter::new(ByRef::new(self), predicate)
+    }
+
+    /// Like [`filter_map`](Self::filter_map), but borrows the lending iterator
+    /// so that it can be resumed afterwards.
+    ///
+    /// See [`ByRef`] for why this is needed instead of `by_ref().filter_map(f)`.
+    #[inline]
+    fn filter_map_by_ref<F>(&mut self, f: F) -> FilterMap<ByRef<'_, Self>, F>
+    where
+        F: for<'a> SingleArgFnMut<Self::Item<'a>>,
+        for<'a> <F as SingleArgFnOnce<Self::Item<'a>>>::Output: OptionTrait,
+    {
+        FilterMap::new(ByRef::new(self), f)
+    }
+
+    /// Like [`map`](Self::map), but borrows the lending iterator
+    /// so that it can be resumed afterwards.
+    ///
+    /// See [`ByRef`] for why this is needed instead of `by_ref().map(f)`.
+    #[inline]
+    fn map_by_ref<F>(&mut self, f: F) -> Map<ByRef<'_, Self>, F>
+    where
+        F: for<'a> SingleArgFnMut<Self::Item<'a>>,
+    {
+        Map::new(ByRef::new(self), f)
+    }
+
+    /// Like [`skip_while`](Self::skip_while), but borrows the lending iterator
+    /// so that it can be resumed afterwards.
+    ///
+    /// See [`ByRef`] for why this is needed instead of `by_ref().skip_while(predicate)`.
+    #[inline]
+    fn skip_while_by_ref<P>(&mut self, predicate: P) -> SkipWhile<ByRef<'_, Self>, P>
+    where
+        P: for<'a> FnMut(&Self::Item<'a>) -> bool,
+    {
+        SkipWhile::new(ByRef::new(self), predicate)
+    }
+
+    /// Like [`take_while`](Self::take_while), but borrows the lending iterator
+    /// so that it can be resumed afterwards.
+    ///
+    /// Just like with [`Iterator::take_while`], the first item that doesn't match
+    /// the predicate is consumed.
+    ///
+    /// See [`ByRef`] for why this is needed instead of `by_ref().take_while(predicate)`.
+    #[inline]
+    fn take_while_by_ref<P>(&mut self, predicate: P) -> TakeWhile<ByRef<'_, Self>, P>
+    where
+        P: for<'a> FnMut(&Self::Item<'a>) -> bool,
+    {
+        TakeWhile::new(ByRef::new(self), predicate)
+    }
+
     /// Tests if every element of the iterator matches a predicate.
     #[inline]
     fn all<P>(&mut self, mut predicate: P) -> bool
@@ -319,17 +943,7 @@ pub trait LendingIterator {
     where
         P: FnMut(&Self::Item<'_>) -> bool,
     {
-        loop {
-            // SAFETY: see https://docs.rs/polonius-the-crab/0.3.1/polonius_the_crab/#the-arcanemagic
-            let self_ = unsafe { &mut *(self as *mut Self) };
-            if let Some(item) = self_.next() {
-                if (predicate)(&item) {
-                    return Some(item);
-                }
-            } else {
-                return None;
-            }
-        }
+        filter_next(self, &mut predicate)
     }
 
     /// Applies function to the elements of iterator and returns
@@ -339,17 +953,12 @@ pub trait LendingIterator {
     where
         F: FnMut(Self::Item<'_>) -> Option<B>,
     {
-        loop {
-            // SAFETY: see https://docs.rs/polonius-the-crab/0.3.1/polonius_the_crab/#the-arcanemagic
-            let self_ = unsafe { &mut *(self as *mut Self) };
-            if let Some(item) = self_.next() {
-                if let Some(result) = f(item) {
-                    return Some(result);
-                }
-            } else {
-                return None;
+        while let Some(item) = self.next() {
+            if let Some(result) = f(item) {
+                return Some(result);
             }
         }
+        None
     }
 
     /// Searches for an element in an iterator, returning its index.
@@ -370,6 +979,7 @@ pub trait LendingIterator {
 
     /// [Lexicographically](Ord#lexicographical-comparison) compares the elements of this [`Iterator`] with those
     /// of another.
+    #[allow(clippy::needless_continue)]
     fn cmp<I>(mut self, mut other: I) -> Ordering
     where
         I: for<'a> LendingIterator<Item<'a> = Self::Item<'a>>,
@@ -393,6 +1003,7 @@ pub trait LendingIterator {
 
     /// [Lexicographically](Ord#lexicographical-comparison) compares the elements of this [`Iterator`] with those
     /// of another with respect to the specified comparison function.
+    #[allow(clippy::needless_continue)]
     fn cmp_by<I, F>(mut self, mut other: I, mut cmp: F) -> Ordering
     where
         Self: Sized,
@@ -416,6 +1027,7 @@ pub trait LendingIterator {
     /// this [`Iterator`] with those of another. The comparison works like short-circuit
     /// evaluation, returning a result without comparing the remaining elements.
     /// As soon as an order can be determined, the evaluation stops and a result is returned.
+    #[allow(clippy::needless_continue)]
     fn partial_cmp<I>(mut self, mut other: I) -> Option<Ordering>
     where
         I: LendingIterator,
@@ -437,6 +1049,7 @@ pub trait LendingIterator {
 
     /// [Lexicographically](Ord#lexicographical-comparison) compares the elements of this [`Iterator`] with those
     /// of another with respect to the specified comparison function.
+    #[allow(clippy::needless_continue)]
     fn partial_cmp_by<I, F>(mut self, mut other: I, mut partial_cmp: F) -> Option<Ordering>
     where
         Self: Sized,
@@ -550,7 +1163,7 @@ pub trait LendingIterator {
     }
 }
 
-impl<T: LendingIterator> LendingIterator for &mut T {
+impl<T: LendingIterator + ?Sized> LendingIterator for &mut T {
     type Item<'a> = T::Item<'a> where Self: 'a;
 
     #[inline]
@@ -562,4 +1175,259 @@ impl<T: LendingIterator> LendingIterator for &mut T {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (**self).size_hint()
     }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        (**self).advance_by(n)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        (**self).nth(n)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: LendingIterator + ?Sized> LendingIterator for Box<T> {
+    type Item<'a> = T::Item<'a> where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        (**self).next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        (**self).advance_by(n)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        (**self).nth(n)
+    }
+}
+
+impl<P> LendingIterator for Pin<P>
+where
+    P: DerefMut,
+    P::Target: LendingIterator + Unpin,
+{
+    type Item<'a> = <P::Target as LendingIterator>::Item<'a> where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        self.as_mut().get_mut().next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.as_mut().get_mut().advance_by(n)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        self.as_mut().get_mut().nth(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ToLendingIterator;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn smart_pointers() {
+        let mut boxed = Box::new((0..5).windows(2));
+        assert_eq!(boxed.nth(1), Some(&[1, 2][..]));
+        assert_eq!(boxed.by_ref().skip(1).next(), Some(&[3, 4][..]));
+        assert_eq!(boxed.next(), None);
+
+        let mut windows = (0..5).windows(3);
+        let mut pinned = Pin::new(&mut windows);
+        assert_eq!(pinned.advance_by(1), Ok(()));
+        assert_eq!(pinned.next(), Some(&[1, 2, 3][..]));
+        assert_eq!(pinned.advance_by(2), Err(NonZeroUsize::new(1).unwrap()));
+    }
+
+    // These lend mutable references that must not be invalidated by the
+    // reborrowing in `find` and friends, run them under Miri to check that.
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn find_mutable_items() {
+        let mut windows = (0..6).windows_mut(2);
+        let window = windows.find(|w| w[0] == 2).unwrap();
+        window[1] = 10;
+        assert_eq!(windows.next(), Some(&mut [10, 4][..]));
+
+        let mut refs = (0..6).lend_refs_mut();
+        *refs.find(|x| **x % 4 == 3).unwrap() += 1;
+        assert_eq!(refs.find(|x| **x > 3), Some(&mut 4));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn find_map_mutable_items() {
+        let mut windows = (0..6).windows_mut(2);
+        let found = windows.find_map(|w| {
+            w[1] += w[0];
+            (w[1] > 4).then_some(w[1])
+        });
+        assert_eq!(found, Some(6));
+        assert_eq!(windows.next(), Some(&mut [6, 4][..]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn filter_mutable_items() {
+        let mut windows = (0..6).windows_mut(2).filter(|w| w[0] % 2 == 1);
+        while let Some(window) = windows.next() {
+            window[1] *= 10;
+        }
+
+        let mut refs = (0..6).lend_refs_mut().skip_while(|x| **x < 4);
+        *refs.next().unwrap() += 1;
+        assert_eq!(refs.next(), Some(&mut 5));
+    }
+
+    fn first_even(x: &mut usize) -> Option<&mut usize> {
+        (*x % 2 == 0).then_some(x)
+    }
+
+    #[test]
+    fn filter_map_mutable_items() {
+        let mut refs = (1..6).lend_refs_mut().filter_map(first_even);
+        let x = refs.next().unwrap();
+        *x += 1;
+        assert_eq!(*x, 3);
+        assert_eq!(refs.next(), Some(&mut 4));
+        assert_eq!(refs.next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn reductions() {
+        fn second(w: &[u64]) -> &u64 {
+            &w[1]
+        }
+        assert_eq!((0..5).windows(2).map(second).sum::<u64>(), 10);
+        assert_eq!((0..5).windows(2).map(second).product::<u64>(), 24);
+        assert_eq!((0..0).windows(2).map(second).product::<u64>(), 1);
+
+        let nums = || vec![3, 1, 4, 1, 5].into_iter().into_lending();
+        assert_eq!(nums().sum::<i32>(), 14);
+        assert_eq!(nums().max_by_key(|x| -x), Some(-1));
+        assert_eq!(nums().min_by_key(|x| -x), Some(-5));
+        assert_eq!((0..5).windows(3).max_by_key(|w| w[0] * 2), Some(4));
+        assert_eq!((0..5).windows(3).last_owned(), Some(vec![2, 3, 4]));
+        assert_eq!((0..0).windows(3).last_owned(), None);
+    }
+
+    // Claims a single item is left, whatever the truth.
+    #[cfg(feature = "alloc")]
+    struct Understated<I>(I);
+
+    #[cfg(feature = "alloc")]
+    impl<I: LendingIterator> LendingIterator for Understated<I> {
+        type Item<'a> = I::Item<'a> where Self: 'a;
+
+        fn next(&mut self) -> Option<Self::Item<'_>> {
+            self.0.next()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (1, Some(1))
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn last_owned_ignores_size_hint() {
+        assert_eq!(Understated((0..5).windows(3)).last_owned(), Some(vec![2, 3, 4]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn partition_and_unzip() {
+        let (small, large): (Vec<_>, Vec<_>) = vec![3, 1, 4, 1, 5]
+            .into_iter()
+            .into_lending()
+            .partition_owned(|&x| x < 3);
+        assert_eq!(small, vec![1, 1]);
+        assert_eq!(large, vec![3, 4, 5]);
+
+        let (left, right): (Vec<_>, Vec<_>) = (0..4).windows(2).zip((10..14).windows(1)).unzip_owned();
+        assert_eq!(left, vec![vec![0, 1], vec![1, 2], vec![2, 3]]);
+        assert_eq!(right, vec![vec![10], vec![11], vec![12]]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn last() {
+        assert_eq!((0..5).windows(2).last_owned(), Some(vec![3, 4]));
+        assert_eq!((0..5).filter(|_| true).windows(2).last_owned(), Some(vec![3, 4]));
+        assert_eq!((0..5).windows(2).last_with(|w| w[0]), Some(3));
+        assert_eq!((0..1).windows(2).last_with(|w| w[0]), None);
+
+        let mut last = None;
+        (0..5).windows(3).for_last(|w| last = Some(w.to_vec()));
+        assert_eq!(last, Some(vec![2, 3, 4]));
+
+        // Sources that don't know their length.
+        let filtered = || (0..10).filter(|x| x % 3 != 0);
+        assert_eq!(filtered().windows(2).last_with(<[i32]>::to_vec), Some(vec![7, 8]));
+        assert_eq!(filtered().windows(7).last_with(|w| w[0]), None);
+        assert_eq!(filtered().windows_mut(6).last_with(|w| w[5]), Some(8));
+        let mut windows = filtered().windows(2);
+        windows.nth(3);
+        assert_eq!(windows.last_with(<[i32]>::to_vec), Some(vec![7, 8]));
+        let mut windows = filtered().windows(2);
+        windows.nth(4);
+        assert_eq!(windows.last_with(<[i32]>::to_vec), None);
+        let mut last = None;
+        filtered().windows_mut(3).for_last(|w| last = Some(w.to_vec()));
+        assert_eq!(last, Some(vec![5, 7, 8]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn par_owned() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        fn square_sum(w: Vec<usize>) -> usize {
+            w.into_iter().map(|x| x * x).sum()
+        }
+        let nums: Vec<usize> = (0..1000).collect();
+        let expected: Vec<_> = nums[..].windows(4).map(|w| square_sum(w.to_vec())).collect();
+        assert_eq!((0..1000).windows(4).par_map_owned(square_sum), expected);
+
+        let lens = (0..10).windows(3).par_map_owned_with(<[i32]>::len, |len| len * 2);
+        assert_eq!(lens, vec![6; 8]);
+
+        let total = AtomicUsize::new(0);
+        (0..1000).windows(4).par_for_each_owned(|w: Vec<usize>| {
+            total.fetch_add(square_sum(w), Ordering::Relaxed);
+        });
+        assert_eq!(total.into_inner(), expected.iter().sum());
+
+        (0..0).windows(4).par_for_each_owned(|_: Vec<usize>| unreachable!());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[should_panic = "worker"]
+    fn par_owned_panic() {
+        (0..1000).windows(2).par_for_each_owned(|w: Vec<usize>| assert!(w[0] < 500, "worker"));
+    }
+
 }
@@ -1,6 +1,26 @@
+mod exact_size_lending_iterator;
 mod functions;
 mod lending_iterator;
+#[cfg(feature = "std")]
+pub(crate) mod par;
+mod resettable;
+mod sum;
+#[cfg(feature = "alloc")]
+mod to_lending_cursors;
+#[cfg(feature = "alloc")]
+mod to_lending_entries;
 mod to_lending_iterator;
+mod to_lending_slice_windows;
+mod to_lending_splits;
+pub use self::exact_size_lending_iterator::ExactSizeLendingIterator;
 pub use self::functions::*;
 pub use self::lending_iterator::LendingIterator;
+pub use self::resettable::Resettable;
+pub use self::sum::{ProductLending, SumLending};
+#[cfg(feature = "alloc")]
+pub use self::to_lending_cursors::ToLendingCursors;
+#[cfg(feature = "alloc")]
+pub use self::to_lending_entries::ToLendingEntries;
 pub use self::to_lending_iterator::ToLendingIterator;
+pub use self::to_lending_slice_windows::ToLendingSliceWindows;
+pub use self::to_lending_splits::{ToLendingSplits, ToLendingStrSplits};
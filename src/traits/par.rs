@@ -0,0 +1,87 @@
+//! Scoped worker threads for the `par_*_owned` methods of [`LendingIterator`].
+//!
+//! The lending iterator itself stays on the calling thread, which converts each element
+//! into an owned value and sends it to the workers through a bounded channel,
+//! so at most a few elements per worker are in flight at a time.
+//!
+//! [`LendingIterator`]: crate::LendingIterator
+
+use std::{
+    num::NonZeroUsize,
+    panic,
+    sync::{mpsc, Arc, Mutex, PoisonError},
+    thread,
+    vec::Vec,
+};
+
+/// How many owned elements each worker may have queued.
+const QUEUED_PER_WORKER: usize = 2;
+
+pub(crate) fn for_each<T, N, F>(mut next: N, f: F)
+where
+    N: FnMut() -> Option<T>,
+    T: Send,
+    F: Fn(T) + Sync,
+{
+    let workers = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let (sender, receiver) = mpsc::sync_channel(workers * QUEUED_PER_WORKER);
+    // Only the workers hold on to the receiver, so that sending fails
+    // instead of blocking forever if all of them panicked.
+    let receiver = Arc::new(Mutex::new(receiver));
+    thread::scope(|scope| {
+        let mut handles = Vec::with_capacity(workers);
+        for _ in 0..workers {
+            let receiver = Arc::clone(&receiver);
+            let f = &f;
+            handles.push(scope.spawn(move || loop {
+                // The guard is dropped at the end of the statement, before calling `f`.
+                let item = receiver
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .recv();
+                match item {
+                    Ok(item) => f(item),
+                    Err(mpsc::RecvError) => break,
+                }
+            }));
+        }
+        drop(receiver);
+        while let Some(item) = next() {
+            // Every worker panicked, the panic is propagated below.
+            if sender.send(item).is_err() {
+                break;
+            }
+        }
+        drop(sender);
+        let mut panics = handles.into_iter().filter_map(|handle| handle.join().err());
+        if let Some(payload) = panics.next() {
+            panic::resume_unwind(payload);
+        }
+    });
+}
+
+pub(crate) fn map<T, R, N, F>(mut next: N, f: F) -> Vec<R>
+where
+    N: FnMut() -> Option<T>,
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let results = Mutex::new(Vec::new());
+    let mut index = 0;
+    let next = || {
+        let item = next()?;
+        index += 1;
+        Some((index - 1, item))
+    };
+    for_each(next, |(index, item)| {
+        let result = f(item);
+        results
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((index, result));
+    });
+    let mut results = results.into_inner().unwrap_or_else(PoisonError::into_inner);
+    results.sort_unstable_by_key(|&(index, _)| index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
@@ -0,0 +1,37 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use crate::LendingIterator;
+
+/// A lending iterator that can rewind to its first element.
+///
+/// This allows [`reset_cycle`] to repeat a lending iterator without cloning it,
+/// for example [`SliceWindowsMut`], which lends windows of a buffer it keeps whole.
+/// Adapters that don't lose information while iterating, such as [`Map`], [`Filter`]
+/// and [`Take`], are resettable when the underlying lending iterator is.
+///
+/// [`reset_cycle`]: crate::LendingIterator::reset_cycle
+/// [`SliceWindowsMut`]: crate::SliceWindowsMut
+/// [`Take`]: crate::Take
+/// [`Map`]: crate::Map
+/// [`Filter`]: crate::Filter
+pub trait Resettable: LendingIterator {
+    /// Rewinds the lending iterator, so that the next call to `next`
+    /// returns its first element again.
+    fn reset(&mut self);
+}
+
+impl<I: Resettable + ?Sized> Resettable for &mut I {
+    #[inline]
+    fn reset(&mut self) {
+        (**self).reset();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Resettable + ?Sized> Resettable for Box<I> {
+    #[inline]
+    fn reset(&mut self) {
+        (**self).reset();
+    }
+}
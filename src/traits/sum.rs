@@ -0,0 +1,112 @@
+/// Trait to represent types that can be created by summing up the items of a
+/// lending iterator.
+///
+/// Unlike [`Sum`](core::iter::Sum), this adds one lent item at a time,
+/// so items don't have to outlive the next call to `next`.
+/// It's implemented for the numeric types, summing `T`, `&T` and `&mut T`.
+///
+/// Used by [`LendingIterator::sum`](crate::LendingIterator::sum).
+pub trait SumLending<A>: Sized {
+    /// The sum of no items.
+    fn zero() -> Self;
+
+    /// Adds a lent item to the sum so far.
+    #[must_use]
+    fn add_lent(self, item: A) -> Self;
+}
+
+/// Trait to represent types that can be created by multiplying the items of a
+/// lending iterator.
+///
+/// Unlike [`Product`](core::iter::Product), this multiplies one lent item at a time,
+/// so items don't have to outlive the next call to `next`.
+/// It's implemented for the numeric types, multiplying `T`, `&T` and `&mut T`.
+///
+/// Used by [`LendingIterator::product`](crate::LendingIterator::product).
+pub trait ProductLending<A>: Sized {
+    /// The product of no items.
+    fn one() -> Self;
+
+    /// Multiplies the product so far by a lent item.
+    #[must_use]
+    fn mul_lent(self, item: A) -> Self;
+}
+
+macro_rules! impl_lending_reductions {
+    ($zero:expr, $one:expr, $($t:ty)*) => {$(
+        impl SumLending<$t> for $t {
+            #[inline]
+            fn zero() -> Self {
+                $zero
+            }
+
+            #[inline]
+            fn add_lent(self, item: $t) -> Self {
+                self + item
+            }
+        }
+
+        impl SumLending<&$t> for $t {
+            #[inline]
+            fn zero() -> Self {
+                $zero
+            }
+
+            #[inline]
+            fn add_lent(self, item: &$t) -> Self {
+                self + *item
+            }
+        }
+
+        impl SumLending<&mut $t> for $t {
+            #[inline]
+            fn zero() -> Self {
+                $zero
+            }
+
+            #[inline]
+            fn add_lent(self, item: &mut $t) -> Self {
+                self + *item
+            }
+        }
+
+        impl ProductLending<$t> for $t {
+            #[inline]
+            fn one() -> Self {
+                $one
+            }
+
+            #[inline]
+            fn mul_lent(self, item: $t) -> Self {
+                self * item
+            }
+        }
+
+        impl ProductLending<&$t> for $t {
+            #[inline]
+            fn one() -> Self {
+                $one
+            }
+
+            #[inline]
+            fn mul_lent(self, item: &$t) -> Self {
+                self * *item
+            }
+        }
+
+        impl ProductLending<&mut $t> for $t {
+            #[inline]
+            fn one() -> Self {
+                $one
+            }
+
+            #[inline]
+            fn mul_lent(self, item: &mut $t) -> Self {
+                self * *item
+            }
+        }
+    )*};
+}
+
+impl_lending_reductions!(0, 1, i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+impl_lending_reductions!(0.0, 1.0, f32 f64);
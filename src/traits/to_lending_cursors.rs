@@ -0,0 +1,87 @@
+use crate::{CursorCollection, Cursors, IntoCursors, LendingIterator};
+
+/// An extension trait for collections that allows lending a cursor to each element,
+/// which can edit the collection around it while iterating.
+///
+/// Unlike [`lend_refs_mut`](crate::ToLendingIterator::lend_refs_mut), the cursors
+/// can remove elements and insert new ones.
+///
+/// Adapters taking closures over the cursors, like
+/// [`filter`](LendingIterator::filter), need a `'static` lending iterator. See the
+/// limitations of [`Chain`](crate::Chain#limitations). [`lend_cursors`] borrows the
+/// collection, so it only works with adapters like
+/// [`enumerate`](LendingIterator::enumerate) and [`take`](LendingIterator::take).
+/// [`into_lending_cursors`] owns the collection instead, so it works with all
+/// adapters when the elements are `'static`.
+///
+/// [`lend_cursors`]: Self::lend_cursors
+/// [`into_lending_cursors`]: Self::into_lending_cursors
+pub trait ToLendingCursors {
+    /// The lending iterator over the cursors.
+    type Cursors<'a>: LendingIterator
+    where
+        Self: 'a;
+
+    /// The lending iterator over the cursors that owns the collection.
+    type IntoCursors: LendingIterator;
+
+    /// Returns a lending iterator over a cursor to each element of the collection.
+    ///
+    /// ```
+    /// use gat_lending_iterator::{LendingIterator, ToLendingCursors};
+    ///
+    /// let mut words = vec!["one", "", "two", "three", ""];
+    /// let mut cursors = words.lend_cursors();
+    /// while let Some(mut cursor) = cursors.next() {
+    ///     if cursor.get().is_empty() {
+    ///         cursor.remove();
+    ///     } else if cursor.get().len() > 3 {
+    ///         cursor.insert_after("!");
+    ///     }
+    /// }
+    /// drop(cursors);
+    /// assert_eq!(words, ["one", "two", "three", "!"]);
+    /// ```
+    fn lend_cursors(&mut self) -> Self::Cursors<'_>;
+
+    /// Returns a lending iterator over a cursor to each element of the collection,
+    /// which takes ownership of the collection. It's given back by
+    /// [`IntoCursors::into_inner`].
+    ///
+    /// ```
+    /// use gat_lending_iterator::{LendingIterator, ToLendingCursors, VecCursor};
+    ///
+    /// let mut cursors = vec!["a", "", "b", "", ""].into_lending_cursors();
+    /// let mut empty = cursors
+    ///     .filter_by_ref(|cursor: &VecCursor<'_, &str>| cursor.get().is_empty())
+    ///     .enumerate();
+    /// while let Some((i, mut cursor)) = empty.next() {
+    ///     if i == 0 {
+    ///         *cursor.get_mut() = "-";
+    ///     } else {
+    ///         cursor.remove();
+    ///     }
+    /// }
+    /// drop(empty);
+    /// assert_eq!(cursors.into_inner(), ["a", "-", "b"]);
+    /// ```
+    fn into_lending_cursors(self) -> Self::IntoCursors
+    where
+        Self: Sized;
+}
+
+impl<C: CursorCollection> ToLendingCursors for C {
+    type Cursors<'a> = Cursors<'a, C>
+    where
+        Self: 'a;
+
+    type IntoCursors = IntoCursors<C>;
+
+    fn lend_cursors(&mut self) -> Self::Cursors<'_> {
+        Cursors::new(self)
+    }
+
+    fn into_lending_cursors(self) -> Self::IntoCursors {
+        IntoCursors::new(self)
+    }
+}
@@ -0,0 +1,71 @@
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::{BTreeMapEntries, LendingIterator};
+#[cfg(feature = "std")]
+use crate::HashMapEntries;
+
+/// An extension trait for maps that allows lending their entries, so that they can
+/// be modified or removed while iterating.
+///
+/// A regular iterator can't do this, since each entry borrows the whole map.
+/// The map is updated as the lending iterator moves on, and is complete again once
+/// it's dropped.
+pub trait ToLendingEntries {
+    /// The lending iterator over the entries of the map.
+    type Entries<'a>: LendingIterator
+    where
+        Self: 'a;
+
+    /// Returns a lending iterator over the entries of the map, which lends a
+    /// [`MapEntry`](crate::MapEntry) for each key.
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use gat_lending_iterator::{LendingIterator, ToLendingEntries};
+    ///
+    /// let mut stock = BTreeMap::from([("apples", 3), ("pears", 0), ("plums", 5)]);
+    /// let mut entries = stock.lend_entries();
+    /// while let Some(mut entry) = entries.next() {
+    ///     if *entry.get() == 0 {
+    ///         entry.remove();
+    ///     } else {
+    ///         *entry.get_mut() -= 1;
+    ///     }
+    /// }
+    /// drop(entries);
+    /// assert_eq!(stock, BTreeMap::from([("apples", 2), ("plums", 4)]));
+    /// ```
+    fn lend_entries(&mut self) -> Self::Entries<'_>;
+}
+
+/// Lends entries in the map's iteration order. Each kept entry is hashed again
+/// when it's put back.
+#[cfg(feature = "std")]
+impl<K, V, S> ToLendingEntries for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Entries<'a> = HashMapEntries<'a, K, V, S>
+    where
+        Self: 'a;
+
+    fn lend_entries(&mut self) -> Self::Entries<'_> {
+        HashMapEntries::new(self)
+    }
+}
+
+/// Lends entries in key order. Each kept entry is inserted again when it's put back.
+impl<K: Ord, V> ToLendingEntries for BTreeMap<K, V> {
+    type Entries<'a> = BTreeMapEntries<'a, K, V>
+    where
+        Self: 'a;
+
+    fn lend_entries(&mut self) -> Self::Entries<'_> {
+        BTreeMapEntries::new(self)
+    }
+}
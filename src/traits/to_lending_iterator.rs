@@ -1,4 +1,6 @@
-use crate::{IntoLending, LendRefs, LendRefsMut, Windows, WindowsMut};
+use crate::{IntoLending, LendRefs, LendRefsMut};
+#[cfg(feature = "alloc")]
+use crate::{ArrayWindows, ArrayWindowsMut, Windows, WindowsMut};
 
 /// An extension trait for iterators that allows turning them into lending iterators (over windows of elements).
 pub trait ToLendingIterator: IntoIterator {
@@ -8,6 +10,7 @@ pub trait ToLendingIterator: IntoIterator {
     /// This was chosen as a compromise between memory usage and time complexity:
     /// if the buffer was limited to size `size`, we would need to shift all the elements
     /// on every iteration.
+    #[cfg(feature = "alloc")]
     fn windows(self, size: usize) -> Windows<Self::IntoIter>
     where
         Self: Sized,
@@ -21,6 +24,7 @@ pub trait ToLendingIterator: IntoIterator {
     /// This was chosen as a compromise between memory usage and time complexity:
     /// if the buffer was limited to size `size`, we would need to shift all the elements
     /// on every iteration.
+    #[cfg(feature = "alloc")]
     fn windows_mut(self, size: usize) -> WindowsMut<Self::IntoIter>
     where
         Self: Sized,
@@ -28,6 +32,38 @@ pub trait ToLendingIterator: IntoIterator {
         WindowsMut::new(self.into_iter(), size)
     }
 
+    /// Turns this iterator into a lending iterator over windows of `N` elements (&\[Item; N\]).
+    ///
+    /// Like [`windows`](Self::windows), but the size of the windows is known at compile time,
+    /// so they can be destructured with patterns such as `&[a, b, c]`.
+    ///
+    /// `N` must not be zero, which is checked at compile time:
+    ///
+    /// ```compile_fail
+    /// use gat_lending_iterator::ToLendingIterator;
+    ///
+    /// let windows = (0..5).array_windows::<0>();
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn array_windows<const N: usize>(self) -> ArrayWindows<Self::IntoIter, N>
+    where
+        Self: Sized,
+    {
+        ArrayWindows::new(self.into_iter())
+    }
+
+    /// Turns this iterator into a lending iterator over mutable windows of `N` elements (&mut \[Item; N\]).
+    ///
+    /// Like [`windows_mut`](Self::windows_mut), but the size of the windows is known at compile time.
+    /// `N` must not be zero, which is checked at compile time.
+    #[cfg(feature = "alloc")]
+    fn array_windows_mut<const N: usize>(self) -> ArrayWindowsMut<Self::IntoIter, N>
+    where
+        Self: Sized,
+    {
+        ArrayWindowsMut::new(self.into_iter())
+    }
+
     /// Turns this iterator into a lending iterator trivially.
     fn into_lending(self) -> IntoLending<Self::IntoIter>
     where
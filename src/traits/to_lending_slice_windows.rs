@@ -0,0 +1,52 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{LendingIterator, SliceWindowsMut};
+
+/// An extension trait for slice buffers that allows lending overlapping mutable
+/// windows of them in place.
+///
+/// Implemented for `&mut [T]`, and with the `alloc` feature for `Vec<T>` and `Box<[T]>`.
+pub trait ToLendingSliceWindows: Sized {
+    /// The lending iterator over the windows.
+    type Windows: LendingIterator;
+
+    /// Returns a lending iterator over the overlapping windows of length `size`,
+    /// like [`slice::windows`], but lending each window mutably.
+    ///
+    /// Changes made through a window are seen by the windows after it. The
+    /// lending iterator is [`Resettable`](crate::Resettable), so it can be
+    /// repeated with [`reset_cycle`](LendingIterator::reset_cycle) without cloning.
+    ///
+    /// ```
+    /// use gat_lending_iterator::{LendingIterator, ToLendingSliceWindows};
+    ///
+    /// let mut fib = [1, 1, 0, 0, 0, 0];
+    /// let mut windows = fib[..].lend_windows_mut(3);
+    /// while let Some(w) = windows.next() {
+    ///     w[2] = w[0] + w[1];
+    /// }
+    /// assert_eq!(fib, [1, 1, 2, 3, 5, 8]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    fn lend_windows_mut(self, size: usize) -> Self::Windows;
+}
+
+macro_rules! impl_to_lending_slice_windows {
+    ($([$($generics:tt)*] $buffer:ty),*) => {$(
+        impl<$($generics)*> ToLendingSliceWindows for $buffer {
+            type Windows = SliceWindowsMut<Self>;
+
+            fn lend_windows_mut(self, size: usize) -> Self::Windows {
+                SliceWindowsMut::new(self, size)
+            }
+        }
+    )*};
+}
+
+impl_to_lending_slice_windows!(['a, T] &'a mut [T]);
+#[cfg(feature = "alloc")]
+impl_to_lending_slice_windows!([T] Vec<T>, [T] Box<[T]>);
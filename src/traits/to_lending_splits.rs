@@ -0,0 +1,188 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use crate::{to_lending::SplitMode, LendingIterator, SplitMut, SplitStrMut};
+
+/// An extension trait for slice buffers that allows splitting them into tokens by
+/// a delimiter predicate, lending each token mutably, like the `split` family of
+/// methods on slices.
+///
+/// The tokens are lent one at a time, and can be edited in place.
+/// Implemented for `&mut [T]`, and with the `alloc` feature for `Vec<T>` and
+/// `Box<[T]>`. The lending iterator owns the buffer, so splitting an owned buffer
+/// gives a `'static` lending iterator that works with adapters taking closures,
+/// like [`filter`](LendingIterator::filter).
+///
+/// Strings are split by [`ToLendingStrSplits`](crate::ToLendingStrSplits).
+///
+/// ```
+/// use gat_lending_iterator::{LendingIterator, ToLendingSplits};
+///
+/// let mut data = [3, 1, 0, 2, 5, 4, 0, 6];
+/// let mut runs = data[..].lend_split_mut(|x| *x == 0);
+/// while let Some(run) = runs.next() {
+///     run.sort_unstable();
+/// }
+/// assert_eq!(data, [1, 3, 0, 2, 4, 5, 0, 6]);
+/// ```
+pub trait ToLendingSplits: Sized {
+    /// The type of the elements the buffer is split between.
+    type Elem;
+
+    /// The lending iterator over the tokens.
+    type Splits<P>: LendingIterator
+    where
+        P: FnMut(&Self::Elem) -> bool;
+
+    /// Returns a lending iterator over the tokens separated by elements matching
+    /// `predicate`, like [`slice::split_mut`].
+    ///
+    /// The delimiters aren't part of the tokens. Consecutive delimiters, and
+    /// delimiters at the start or end, separate empty tokens.
+    fn lend_split_mut<P>(self, predicate: P) -> Self::Splits<P>
+    where
+        P: FnMut(&Self::Elem) -> bool;
+
+    /// Returns a lending iterator over the tokens separated by elements matching
+    /// `predicate`, like [`slice::split_inclusive_mut`].
+    ///
+    /// Each token ends with its delimiter. If the buffer ends with a delimiter,
+    /// there is no empty token after it.
+    fn lend_split_inclusive_mut<P>(self, predicate: P) -> Self::Splits<P>
+    where
+        P: FnMut(&Self::Elem) -> bool;
+
+    /// Returns a lending iterator over the tokens separated by elements matching
+    /// `predicate`, like [`str::split_terminator`].
+    ///
+    /// The same as [`lend_split_mut`](Self::lend_split_mut), except that if the
+    /// buffer ends with a delimiter, there is no empty token after it.
+    fn lend_split_terminator_mut<P>(self, predicate: P) -> Self::Splits<P>
+    where
+        P: FnMut(&Self::Elem) -> bool;
+}
+
+macro_rules! impl_to_lending_splits {
+    ($elem:ty, $([$($generics:tt)*] $buffer:ty),*) => {$(
+        impl<$($generics)*> ToLendingSplits for $buffer {
+            type Elem = $elem;
+
+            type Splits<P> = SplitMut<Self, P>
+            where
+                P: FnMut(&Self::Elem) -> bool;
+
+            fn lend_split_mut<P>(self, predicate: P) -> Self::Splits<P>
+            where
+                P: FnMut(&Self::Elem) -> bool,
+            {
+                SplitMut::new(self, predicate, SplitMode::Split)
+            }
+
+            fn lend_split_inclusive_mut<P>(self, predicate: P) -> Self::Splits<P>
+            where
+                P: FnMut(&Self::Elem) -> bool,
+            {
+                SplitMut::new(self, predicate, SplitMode::Inclusive)
+            }
+
+            fn lend_split_terminator_mut<P>(self, predicate: P) -> Self::Splits<P>
+            where
+                P: FnMut(&Self::Elem) -> bool,
+            {
+                SplitMut::new(self, predicate, SplitMode::Terminator)
+            }
+        }
+    )*};
+}
+
+impl_to_lending_splits!(T, ['a, T] &'a mut [T]);
+#[cfg(feature = "alloc")]
+impl_to_lending_splits!(T, [T] Vec<T>, [T] Box<[T]>);
+
+/// An extension trait for string buffers that allows splitting them into tokens
+/// by a delimiter predicate, lending each token mutably, like the `split` family
+/// of methods on `str`.
+///
+/// The tokens are lent one at a time, and can be edited in place.
+/// Implemented for `&mut str`, and with the `alloc` feature for `String` and
+/// `Box<str>`. As with [`ToLendingSplits`], splitting an owned buffer gives a
+/// `'static` lending iterator.
+///
+/// ```
+/// use gat_lending_iterator::{LendingIterator, ToLendingStrSplits};
+///
+/// let mut text = String::from("Lending ITERATORS lend");
+/// let mut words = text.as_mut_str().lend_split_mut(|c| c == ' ');
+/// while let Some(word) = words.next() {
+///     word.make_ascii_lowercase();
+/// }
+/// assert_eq!(text, "lending iterators lend");
+/// ```
+pub trait ToLendingStrSplits: Sized {
+    /// The lending iterator over the tokens.
+    type Splits<P>: LendingIterator
+    where
+        P: FnMut(char) -> bool;
+
+    /// Returns a lending iterator over the tokens separated by characters
+    /// matching `predicate`, like [`str::split`].
+    ///
+    /// The delimiters aren't part of the tokens. Consecutive delimiters, and
+    /// delimiters at the start or end, separate empty tokens.
+    fn lend_split_mut<P>(self, predicate: P) -> Self::Splits<P>
+    where
+        P: FnMut(char) -> bool;
+
+    /// Returns a lending iterator over the tokens separated by characters
+    /// matching `predicate`, like [`str::split_inclusive`].
+    ///
+    /// Each token ends with its delimiter. If the buffer ends with a delimiter,
+    /// there is no empty token after it.
+    fn lend_split_inclusive_mut<P>(self, predicate: P) -> Self::Splits<P>
+    where
+        P: FnMut(char) -> bool;
+
+    /// Returns a lending iterator over the tokens separated by characters
+    /// matching `predicate`, like [`str::split_terminator`].
+    ///
+    /// The same as [`lend_split_mut`](Self::lend_split_mut), except that if the
+    /// buffer ends with a delimiter, there is no empty token after it.
+    fn lend_split_terminator_mut<P>(self, predicate: P) -> Self::Splits<P>
+    where
+        P: FnMut(char) -> bool;
+}
+
+macro_rules! impl_to_lending_str_splits {
+    ($([$($generics:tt)*] $buffer:ty),*) => {$(
+        impl<$($generics)*> ToLendingStrSplits for $buffer {
+            type Splits<P> = SplitStrMut<Self, P>
+            where
+                P: FnMut(char) -> bool;
+
+            fn lend_split_mut<P>(self, predicate: P) -> Self::Splits<P>
+            where
+                P: FnMut(char) -> bool,
+            {
+                SplitStrMut::new(self, predicate, SplitMode::Split)
+            }
+
+            fn lend_split_inclusive_mut<P>(self, predicate: P) -> Self::Splits<P>
+            where
+                P: FnMut(char) -> bool,
+            {
+                SplitStrMut::new(self, predicate, SplitMode::Inclusive)
+            }
+
+            fn lend_split_terminator_mut<P>(self, predicate: P) -> Self::Splits<P>
+            where
+                P: FnMut(char) -> bool,
+            {
+                SplitStrMut::new(self, predicate, SplitMode::Terminator)
+            }
+        }
+    )*};
+}
+
+impl_to_lending_str_splits!(['a] &'a mut str);
+#[cfg(feature = "alloc")]
+impl_to_lending_str_splits!([] String, [] Box<str>);